@@ -0,0 +1,279 @@
+/// Palette index that stands for the color of the surrounding text.
+const FOREGROUND: u16 = 0xFFFF;
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    let bytes = data.get(offset..offset + 2)?;
+    Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// One layer of a color glyph, drawn in order from bottom to top.
+#[derive(Copy, Clone, Debug)]
+pub struct Layer {
+    /// Glyph whose outline fills this layer.
+    pub glyph: u16,
+    /// Index into the palette, or `None` for the text color.
+    pub palette_index: Option<u16>,
+}
+
+/// Reader for color glyphs stored as `COLR` version 0 layers with `CPAL`
+/// palettes.
+pub struct ColorGlyphs<'a> {
+    colr: &'a [u8],
+    cpal: Option<&'a [u8]>,
+    base_glyphs: usize,
+    base_glyphs_offset: usize,
+    layers_offset: usize,
+    layers: usize,
+}
+
+impl<'a> ColorGlyphs<'a> {
+    /// Parses the `COLR` table and an optional `CPAL` table. Returns `None`
+    /// if the `COLR` table is malformed.
+    pub fn parse(colr: &'a [u8], cpal: Option<&'a [u8]>) -> Option<ColorGlyphs<'a>> {
+        // Version 1 tables start with a version 0 header, so their layered
+        // glyphs can still be read.
+        let base_glyphs = read_u16(colr, 2)? as usize;
+        let base_glyphs_offset = read_u32(colr, 4)? as usize;
+        let layers_offset = read_u32(colr, 8)? as usize;
+        let layers = read_u16(colr, 12)? as usize;
+
+        Some(ColorGlyphs {
+            colr,
+            cpal,
+            base_glyphs,
+            base_glyphs_offset,
+            layers_offset,
+            layers,
+        })
+    }
+
+    /// Returns the layers of `glyph`, or `None` if it isn't a color glyph.
+    pub fn layers(&self, glyph: u16) -> Option<impl Iterator<Item = Layer> + '_> {
+        // Base glyph records are sorted by glyph ID.
+        let (mut low, mut high) = (0, self.base_glyphs);
+        let record = loop {
+            if low >= high {
+                return None;
+            }
+
+            let middle = (low + high) / 2;
+            let record = self.base_glyphs_offset + 6 * middle;
+            let id = read_u16(self.colr, record)?;
+            if id < glyph {
+                low = middle + 1;
+            } else if id > glyph {
+                high = middle;
+            } else {
+                break record;
+            }
+        };
+
+        let first = read_u16(self.colr, record + 2)? as usize;
+        let count = read_u16(self.colr, record + 4)? as usize;
+        let end = (first + count).min(self.layers);
+
+        Some((first..end).filter_map(move |index| {
+            let layer = self.layers_offset + 4 * index;
+            let palette_index = read_u16(self.colr, layer + 2)?;
+            Some(Layer {
+                glyph: read_u16(self.colr, layer)?,
+                palette_index: (palette_index != FOREGROUND).then_some(palette_index),
+            })
+        }))
+    }
+
+    /// Returns the number of palettes in `CPAL`.
+    pub fn palettes(&self) -> u16 {
+        self.cpal.and_then(|cpal| read_u16(cpal, 4)).unwrap_or(0)
+    }
+
+    /// Returns entry `index` of `palette` as RGBA in the range 0 to 1, or
+    /// `None` if either is out of range.
+    pub fn color(&self, palette: u16, index: u16) -> Option<[f32; 4]> {
+        let cpal = self.cpal?;
+        let entries = read_u16(cpal, 2)?;
+        let palettes = read_u16(cpal, 4)?;
+        let records = read_u16(cpal, 6)? as usize;
+        let records_offset = read_u32(cpal, 8)? as usize;
+        if palette >= palettes || index >= entries {
+            return None;
+        }
+
+        let first = read_u16(cpal, 12 + 2 * palette as usize)? as usize;
+        let record = first + index as usize;
+        if record >= records {
+            return None;
+        }
+
+        // Records are stored in BGRA order.
+        let bytes = cpal.get(records_offset + 4 * record..records_offset + 4 * record + 4)?;
+        Some([
+            bytes[2] as f32 / 255.0,
+            bytes[1] as f32 / 255.0,
+            bytes[0] as f32 / 255.0,
+            bytes[3] as f32 / 255.0,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_u16(data: &mut Vec<u8>, value: u16) {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn push_u32(data: &mut Vec<u8>, value: u32) {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+
+    /// Builds a `COLR` table from base glyph records `(glyph, first, count)`
+    /// and layer records `(glyph, palette_index)`.
+    fn colr(base_glyphs: &[(u16, u16, u16)], layers: &[(u16, u16)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        push_u16(&mut data, 0);
+        push_u16(&mut data, base_glyphs.len() as u16);
+        push_u32(&mut data, 14);
+        push_u32(&mut data, 14 + 6 * base_glyphs.len() as u32);
+        push_u16(&mut data, layers.len() as u16);
+        for &(glyph, first, count) in base_glyphs {
+            push_u16(&mut data, glyph);
+            push_u16(&mut data, first);
+            push_u16(&mut data, count);
+        }
+        for &(glyph, palette_index) in layers {
+            push_u16(&mut data, glyph);
+            push_u16(&mut data, palette_index);
+        }
+        data
+    }
+
+    /// Builds a `CPAL` table with `entries` colors per palette, where each
+    /// palette starts at the given record, from BGRA color records.
+    fn cpal(entries: u16, palettes: &[u16], records: &[[u8; 4]]) -> Vec<u8> {
+        let mut data = Vec::new();
+        push_u16(&mut data, 0);
+        push_u16(&mut data, entries);
+        push_u16(&mut data, palettes.len() as u16);
+        push_u16(&mut data, records.len() as u16);
+        push_u32(&mut data, 12 + 2 * palettes.len() as u32);
+        for &first in palettes {
+            push_u16(&mut data, first);
+        }
+        for record in records {
+            data.extend_from_slice(record);
+        }
+        data
+    }
+
+    fn layers(glyphs: &ColorGlyphs, glyph: u16) -> Option<Vec<(u16, Option<u16>)>> {
+        let layers = glyphs.layers(glyph)?;
+        Some(
+            layers
+                .map(|layer| (layer.glyph, layer.palette_index))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn reads_layers_and_foreground() {
+        let colr = colr(
+            &[(3, 0, 2), (7, 2, 1)],
+            &[(10, 0), (11, FOREGROUND), (12, 1)],
+        );
+        let glyphs = ColorGlyphs::parse(&colr, None).unwrap();
+
+        assert_eq!(layers(&glyphs, 3), Some(vec![(10, Some(0)), (11, None)]));
+        assert_eq!(layers(&glyphs, 7), Some(vec![(12, Some(1))]));
+        assert_eq!(layers(&glyphs, 5), None);
+        assert_eq!(layers(&glyphs, 0), None);
+        assert_eq!(layers(&glyphs, 8), None);
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        let colr = colr(&[(3, 0, 1)], &[(10, 0)]);
+        for len in 0..14 {
+            assert!(ColorGlyphs::parse(&colr[..len], None).is_none());
+        }
+    }
+
+    #[test]
+    fn tolerates_truncated_records() {
+        let colr = colr(&[(3, 0, 2)], &[(10, 0), (11, 1)]);
+        for len in 14..colr.len() {
+            let glyphs = ColorGlyphs::parse(&colr[..len], None).unwrap();
+            // Missing base glyph records hide the glyph, and missing layer
+            // records are skipped.
+            if let Some(layers) = layers(&glyphs, 3) {
+                assert!(layers.len() <= 2);
+            }
+        }
+    }
+
+    #[test]
+    fn clamps_layer_range() {
+        // The base glyph claims more layers than the table has.
+        let short = colr(&[(3, 1, 5)], &[(10, 0), (11, 1)]);
+        let glyphs = ColorGlyphs::parse(&short, None).unwrap();
+        assert_eq!(layers(&glyphs, 3), Some(vec![(11, Some(1))]));
+
+        let past_end = colr(&[(3, 9, 1)], &[(10, 0)]);
+        let glyphs = ColorGlyphs::parse(&past_end, None).unwrap();
+        assert_eq!(layers(&glyphs, 3), Some(vec![]));
+    }
+
+    #[test]
+    fn reads_palette_colors() {
+        let colr = colr(&[], &[]);
+        let cpal = cpal(
+            2,
+            &[0, 2],
+            &[[0, 0, 255, 255], [255, 0, 0, 128], [0, 255, 0, 255]],
+        );
+        let glyphs = ColorGlyphs::parse(&colr, Some(&cpal)).unwrap();
+
+        assert_eq!(glyphs.palettes(), 2);
+        assert_eq!(glyphs.color(0, 0), Some([1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(glyphs.color(0, 1), Some([0.0, 0.0, 1.0, 128.0 / 255.0]));
+        assert_eq!(glyphs.color(1, 0), Some([0.0, 1.0, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn rejects_out_of_range_colors() {
+        let colr = colr(&[], &[]);
+        // The second palette starts at the last record, so its second entry
+        // is past the end of the records.
+        let cpal = cpal(
+            2,
+            &[0, 2],
+            &[[0, 0, 255, 255], [255, 0, 0, 255], [0, 255, 0, 255]],
+        );
+        let glyphs = ColorGlyphs::parse(&colr, Some(&cpal)).unwrap();
+
+        assert_eq!(glyphs.color(2, 0), None);
+        assert_eq!(glyphs.color(0, 2), None);
+        assert_eq!(glyphs.color(0, FOREGROUND), None);
+        assert_eq!(glyphs.color(1, 1), None);
+
+        let glyphs = ColorGlyphs::parse(&colr, None).unwrap();
+        assert_eq!(glyphs.palettes(), 0);
+        assert_eq!(glyphs.color(0, 0), None);
+    }
+
+    #[test]
+    fn tolerates_truncated_palettes() {
+        let colr = colr(&[], &[]);
+        let cpal = cpal(1, &[0], &[[1, 2, 3, 4]]);
+        for len in 0..cpal.len() {
+            let glyphs = ColorGlyphs::parse(&colr, Some(&cpal[..len])).unwrap();
+            assert_eq!(glyphs.color(0, 0), None);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+// Returns the offset in model space that moves the corner of an
+// axis-aligned quad at `pos` outward by one pixel on screen in each
+// direction of `outward`, so antialiased edges on the quad boundary are
+// not clipped.
+vec2 dilate(vec3 pos, vec2 outward) {
+    vec4 clip = uTransform * vec4(pos, 1.0);
+    if (clip.w <= 0.0) {
+        return vec2(0.0);
+    }
+
+    // Screen-space Jacobian of the projection with respect to model x and y.
+    vec4 cx = uTransform * vec4(1.0, 0.0, 0.0, 0.0);
+    vec4 cy = uTransform * vec4(0.0, 1.0, 0.0, 0.0);
+    vec2 jx = 0.5 * uScreenSize * (cx.xy * clip.w - clip.xy * cx.w) / (clip.w * clip.w);
+    vec2 jy = 0.5 * uScreenSize * (cy.xy * clip.w - clip.xy * cy.w) / (clip.w * clip.w);
+
+    float det = abs(jx.x * jy.y - jx.y * jy.x);
+    if (det < 1e-6) {
+        return vec2(0.0);
+    }
+
+    // Moving an edge along x by d shifts it d * det / |jy| pixels along its
+    // screen-space normal, and likewise for y.
+    return outward * vec2(length(jy), length(jx)) / det;
+}
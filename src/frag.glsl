@@ -3,14 +3,37 @@
 const uint mask = 0xFFF;
 const uint shift = 12;
 
+const uint FILL_NONZERO = 0;
+const uint FILL_EVEN_ODD = 1;
+
+const uint COVERAGE_LINEAR = 0;
+const uint COVERAGE_QUADRATIC = 1;
+const uint COVERAGE_GAMMA = 2;
+const uint COVERAGE_CONTRAST = 3;
+
+const uint SUBPIXEL_OFF = 0;
+const uint SUBPIXEL_RGB = 1;
+const uint SUBPIXEL_BGR = 2;
+
+// FreeType's default five-tap LCD filter.
+const float FILTER[5] = float[](0.03125, 0.30078125, 0.3359375, 0.30078125, 0.03125);
+
+uniform uint uCoverageMode;
+uniform vec2 uCoverageParams;
+uniform uint uSubpixelMode;
+
 uniform usampler2D uComponents;
 uniform sampler2D uPoints;
 
 layout(location = 0) in vec2 vUv;
 layout(location = 1) flat in uvec2 vComponentsRange;
 layout(location = 2) flat in uvec2 vPointsRange;
+layout(location = 3) flat in uint vFillRule;
+layout(location = 4) flat in vec2 vExtent;
+layout(location = 5) flat in vec4 vColor;
 
-out vec4 oColor;
+layout(location = 0, index = 0) out vec4 oColor;
+layout(location = 0, index = 1) out vec4 oBlend;
 
 vec4 fetch(sampler2D tex, uint index) {
     ivec2 coords = ivec2(index & mask, index >> shift);
@@ -49,13 +72,9 @@ void add_coverage(inout float alpha, inout vec2 prev, vec2 next) {
     prev = next;
 }
 
-void main() {
-    oColor = vec4(1.f, 0.f, 1.f, 1.f);
-    vec2 ddx = dFdx(vUv);
-    vec2 ddy = dFdy(vUv);
-    mat2x2 view = inverse(mat2(ddx, ddy));
-    vec2 offset = vec2(0.5, 0.5) - view * vUv;
-
+// Returns the winding-weighted area of the unit square under the given
+// mapping from path space to pixel space.
+float winding(mat2x2 view, vec2 offset) {
     float alpha = 0.0;
     for (uint i = vComponentsRange.x; i < vComponentsRange.y; i++) {
         uvec2 component = ufetch(uComponents, i).xy;
@@ -112,7 +131,71 @@ void main() {
         }
     }
 
-    alpha = clamp(abs(0.5 * alpha), 0.0, 1.0);
-    alpha = 1.0 - (1.0 - alpha) * (1.0 - alpha);
-    oColor = alpha * vec4(0.f, 0.f, 0.f, 1.f);
+    return 0.5 * alpha;
+}
+
+float resolve(float winding) {
+    if (vFillRule == FILL_EVEN_ODD) {
+        return abs(winding - 2.0 * round(0.5 * winding));
+    } else {
+        return min(abs(winding), 1.0);
+    }
+}
+
+float shape(float alpha, float ppem) {
+    if (uCoverageMode == COVERAGE_QUADRATIC) {
+        alpha = 1.0 - (1.0 - alpha) * (1.0 - alpha);
+    } else if (uCoverageMode == COVERAGE_GAMMA) {
+        alpha = pow(alpha, 1.0 / uCoverageParams.x);
+    } else if (uCoverageMode == COVERAGE_CONTRAST) {
+        float amount = uCoverageParams.x * clamp(1.0 - ppem / uCoverageParams.y, 0.0, 1.0);
+        alpha = pow(alpha, 1.0 / (1.0 + amount));
+    }
+
+    return alpha;
+}
+
+void main() {
+    vec2 ddx = dFdx(vUv);
+    vec2 ddy = dFdy(vUv);
+    mat2x2 view = inverse(mat2(ddx, ddy));
+    vec2 offset = vec2(0.5, 0.5) - view * vUv;
+
+    // Approximate the on-screen size of the glyph quad in pixels, then the
+    // pixels per em from the size of the quad in ems.
+    float size = sqrt(abs(determinant(view)));
+    float ppem = size / sqrt(max(vExtent.x * vExtent.y, 1e-6));
+
+    vec3 coverage;
+    if (uSubpixelMode == SUBPIXEL_OFF) {
+        coverage = vec3(resolve(winding(view, offset)));
+    } else {
+        // Evaluate seven boxes one third of a pixel wide, centered on the
+        // subpixels of this pixel and its neighbors, then filter them.
+        mat2x2 subview = mat2(3.0, 0.0, 0.0, 1.0) * view;
+        float samples[7];
+        for (int k = 0; k < 7; k++) {
+            vec2 suboffset = vec2(3.0 * offset.x - float(k - 2), offset.y);
+            samples[k] = resolve(winding(subview, suboffset));
+        }
+
+        for (int c = 0; c < 3; c++) {
+            float value = 0.0;
+            for (int j = 0; j < 5; j++) {
+                value += FILTER[j] * samples[c + j];
+            }
+            coverage[c] = value;
+        }
+
+        if (uSubpixelMode == SUBPIXEL_BGR) {
+            coverage = coverage.bgr;
+        }
+    }
+
+    coverage = vec3(shape(coverage.r, ppem), shape(coverage.g, ppem), shape(coverage.b, ppem));
+
+    vec4 color = vColor;
+    float alpha = (coverage.r + coverage.g + coverage.b) / 3.0;
+    oColor = vec4(color.rgb * color.a * coverage, color.a * alpha);
+    oBlend = vec4(color.a * coverage, color.a * alpha);
 }
@@ -515,3 +515,177 @@ impl ops::Mul<Vec3> for Mat4x4 {
         } * (1.0 / (self.0[12] * rhs.x + self.0[13] * rhs.y + self.0[14] * rhs.z + self.0[15]))
     }
 }
+
+/// A two-by-three matrix, stored in row-major order.
+///
+/// Represents a two-dimensional affine transformation: the left two columns
+/// are its linear part and the last column its translation.
+#[derive(Copy, Clone)]
+pub struct Mat2x3(pub [f32; 6]);
+
+impl Mat2x3 {
+    /// Constructs the identity matrix.
+    #[inline]
+    pub fn id() -> Mat2x3 {
+        Mat2x3([1.0, 0.0, 0.0, 0.0, 1.0, 0.0])
+    }
+
+    /// Constructs a matrix which translates by the given amounts in each
+    /// dimension.
+    #[inline]
+    pub fn translate(x: f32, y: f32) -> Mat2x3 {
+        Mat2x3([1.0, 0.0, x, 0.0, 1.0, y])
+    }
+
+    /// Constructs a matrix which scales each dimension by the given factor.
+    #[inline]
+    pub fn scale(x: f32, y: f32) -> Mat2x3 {
+        Mat2x3([x, 0.0, 0.0, 0.0, y, 0.0])
+    }
+
+    /// Constructs a matrix that rotates counterclockwise by the given angle,
+    /// with y pointing up.
+    #[inline]
+    pub fn rotate(angle: f32) -> Mat2x3 {
+        let (sin, cos) = angle.sin_cos();
+        Mat2x3([cos, -sin, 0.0, sin, cos, 0.0])
+    }
+
+    /// Constructs a matrix which shears x by `x` times y, and y by `y`
+    /// times x. The factors are the tangents of the shear angles.
+    #[inline]
+    pub fn skew(x: f32, y: f32) -> Mat2x3 {
+        Mat2x3([1.0, x, 0.0, y, 1.0, 0.0])
+    }
+
+    /// Returns the inverse transformation, or `None` if the matrix is
+    /// singular.
+    #[allow(unused)]
+    pub fn invert(&self) -> Option<Mat2x3> {
+        let [a, b, x, c, d, y] = self.0;
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        let inv = 1.0 / det;
+        Some(Mat2x3([
+            d * inv,
+            -b * inv,
+            (b * y - d * x) * inv,
+            -c * inv,
+            a * inv,
+            (c * x - a * y) * inv,
+        ]))
+    }
+
+    /// Applies the linear part of the transformation, ignoring the
+    /// translation, as for directions and offsets.
+    #[inline]
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        Vec2 {
+            x: self.0[0] * vector.x + self.0[1] * vector.y,
+            y: self.0[3] * vector.x + self.0[4] * vector.y,
+        }
+    }
+
+    /// Applies the transformation to a point.
+    #[inline]
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.transform_vector(point) + Vec2::new(self.0[2], self.0[5])
+    }
+
+    /// Returns the determinant of the linear part, which is negative for
+    /// transformations that mirror.
+    #[inline]
+    pub fn determinant(&self) -> f32 {
+        self.0[0] * self.0[4] - self.0[1] * self.0[3]
+    }
+
+    /// Returns the largest factor by which the transformation stretches
+    /// lengths.
+    pub fn max_scale(&self) -> f32 {
+        let [a, b, _, c, d, _] = self.0;
+        let sum = 0.5 * (a * a + b * b + c * c + d * d);
+        let det = a * d - b * c;
+        (sum + (sum * sum - det * det).max(0.0).sqrt()).sqrt()
+    }
+}
+
+/// Composes two transformations, applying `rhs` first.
+impl ops::Mul<Mat2x3> for Mat2x3 {
+    type Output = Mat2x3;
+    #[inline]
+    fn mul(self, rhs: Mat2x3) -> Mat2x3 {
+        let [a, b, x, c, d, y] = self.0;
+        let [a2, b2, x2, c2, d2, y2] = rhs.0;
+        Mat2x3([
+            a * a2 + b * c2,
+            a * b2 + b * d2,
+            a * x2 + b * y2 + x,
+            c * a2 + d * c2,
+            c * b2 + d * d2,
+            c * x2 + d * y2 + y,
+        ])
+    }
+}
+
+impl ops::Mul<Vec2> for Mat2x3 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        self.transform_point(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Vec2, b: Vec2) {
+        assert!(a.distance(b) < 1e-4, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn invert_round_trips() {
+        let m = Mat2x3::translate(3.0, 4.0)
+            * Mat2x3::rotate(0.7)
+            * Mat2x3::skew(0.3, -0.2)
+            * Mat2x3::scale(2.0, 0.5);
+        let inverse = m.invert().unwrap();
+        for point in [
+            Vec2::new(1.5, -2.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(-7.0, 3.0),
+        ] {
+            assert_close(inverse * (m * point), point);
+            assert_close(m * (inverse * point), point);
+        }
+        assert_close(
+            inverse.transform_vector(m.transform_vector(Vec2::new(1.0, 1.0))),
+            Vec2::new(1.0, 1.0),
+        );
+
+        assert!(Mat2x3::scale(0.0, 1.0).invert().is_none());
+        assert!(Mat2x3::scale(f32::INFINITY, 1.0).invert().is_none());
+    }
+
+    #[test]
+    fn composition_applies_right_first() {
+        let translate = Mat2x3::translate(10.0, 0.0);
+        let rotate = Mat2x3::rotate(std::f32::consts::FRAC_PI_2);
+        let point = Vec2::new(1.0, 0.0);
+
+        assert_close((translate * rotate) * point, Vec2::new(10.0, 1.0));
+        assert_close((rotate * translate) * point, Vec2::new(0.0, 11.0));
+        assert_close((translate * rotate) * point, translate * (rotate * point));
+    }
+
+    #[test]
+    fn vectors_ignore_translation() {
+        let m = Mat2x3::translate(5.0, 6.0) * Mat2x3::skew(1.0, 0.0);
+        assert_close(m.transform_vector(Vec2::new(0.0, 1.0)), Vec2::new(1.0, 1.0));
+        assert_close(m.transform_point(Vec2::new(0.0, 1.0)), Vec2::new(6.0, 7.0));
+        assert!(Mat2x3::scale(1.0, -1.0).determinant() < 0.0);
+    }
+}
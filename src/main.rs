@@ -1,16 +1,20 @@
+mod colr;
 mod geom;
 mod path;
 mod render;
+mod svg;
 mod window;
 
 use std::collections::{HashMap, VecDeque};
-use std::ffi::{c_void, CString, CStr};
+use std::ffi::{c_void, CString};
 
-use ttf_parser::Font;
+use ttf_parser::Face;
 
+use colr::*;
 use geom::*;
 use path::*;
 use render::*;
+use svg::*;
 use window::*;
 
 macro_rules! offset_of {
@@ -32,6 +36,10 @@ struct GlyphVertex {
     uv: [f32; 2],
     components_range: [u32; 2],
     points_range: [u32; 2],
+    fill_rule: u32,
+    extent: [f32; 2],
+    uv_scale: [f32; 2],
+    color: [f32; 4],
 }
 
 unsafe impl VertexFormat for GlyphVertex {
@@ -61,14 +69,241 @@ unsafe impl VertexFormat for GlyphVertex {
                 dimension: 2,
                 offset: unsafe { offset_of!(GlyphVertex, points_range) },
             },
+            VertexAttrib {
+                location: 4,
+                type_: AttribType::Uint,
+                dimension: 1,
+                offset: unsafe { offset_of!(GlyphVertex, fill_rule) },
+            },
+            VertexAttrib {
+                location: 5,
+                type_: AttribType::Float,
+                dimension: 2,
+                offset: unsafe { offset_of!(GlyphVertex, extent) },
+            },
+            VertexAttrib {
+                location: 6,
+                type_: AttribType::Float,
+                dimension: 2,
+                offset: unsafe { offset_of!(GlyphVertex, uv_scale) },
+            },
+            VertexAttrib {
+                location: 7,
+                type_: AttribType::Float,
+                dimension: 4,
+                offset: unsafe { offset_of!(GlyphVertex, color) },
+            },
+        ]
+    }
+}
+
+impl GlyphVertex {
+    /// Builds the corners of a quad drawing `entry` over `min..max` in layout
+    /// units.
+    fn quad(
+        entry: &GlyphEntry,
+        min: Vec2,
+        max: Vec2,
+        extent: [f32; 2],
+        color: [f32; 4],
+    ) -> [GlyphVertex; 4] {
+        let size = max - min;
+
+        // Empty glyphs have no extent to extrapolate the UVs over. A zero
+        // `uv_scale` also disables dilation in the vertex shader.
+        let uv_scale = if size.x > 0.0 && size.y > 0.0 {
+            [1.0 / size.x, 1.0 / size.y]
+        } else {
+            [0.0, 0.0]
+        };
+
+        let vertex = |pos: Vec2, uv: Vec2| GlyphVertex {
+            pos: [pos.x, pos.y, 0.0],
+            uv: [uv.x, uv.y],
+            components_range: entry.components_range,
+            points_range: entry.points_range,
+            fill_rule: entry.path.fill_rule as u32,
+            extent,
+            uv_scale,
+            color,
+        };
+
+        [
+            vertex(min, Vec2::new(0.0, 0.0)),
+            vertex(Vec2::new(max.x, min.y), Vec2::new(1.0, 0.0)),
+            vertex(max, Vec2::new(1.0, 1.0)),
+            vertex(Vec2::new(min.x, max.y), Vec2::new(0.0, 1.0)),
         ]
     }
 }
 
+/// Per-glyph record for the instanced path. The vertex shader expands each
+/// instance into a quad covering `bbox` (in font units), scaled by `scale`
+/// and placed at `origin`.
+#[derive(Copy, Clone)]
+#[repr(C)]
+struct GlyphInstance {
+    origin: [f32; 3],
+    scale: f32,
+    bbox: [f32; 4],
+    components_range: [u32; 2],
+    points_range: [u32; 2],
+    fill_rule: u32,
+    extent: [f32; 2],
+    color: [f32; 4],
+}
+
+unsafe impl VertexFormat for GlyphInstance {
+    fn attribs() -> Vec<VertexAttrib> {
+        vec![
+            VertexAttrib {
+                location: 0,
+                type_: AttribType::Float,
+                dimension: 3,
+                offset: unsafe { offset_of!(GlyphInstance, origin) },
+            },
+            VertexAttrib {
+                location: 1,
+                type_: AttribType::Float,
+                dimension: 1,
+                offset: unsafe { offset_of!(GlyphInstance, scale) },
+            },
+            VertexAttrib {
+                location: 2,
+                type_: AttribType::Float,
+                dimension: 4,
+                offset: unsafe { offset_of!(GlyphInstance, bbox) },
+            },
+            VertexAttrib {
+                location: 3,
+                type_: AttribType::Uint,
+                dimension: 2,
+                offset: unsafe { offset_of!(GlyphInstance, components_range) },
+            },
+            VertexAttrib {
+                location: 4,
+                type_: AttribType::Uint,
+                dimension: 2,
+                offset: unsafe { offset_of!(GlyphInstance, points_range) },
+            },
+            VertexAttrib {
+                location: 5,
+                type_: AttribType::Uint,
+                dimension: 1,
+                offset: unsafe { offset_of!(GlyphInstance, fill_rule) },
+            },
+            VertexAttrib {
+                location: 6,
+                type_: AttribType::Float,
+                dimension: 2,
+                offset: unsafe { offset_of!(GlyphInstance, extent) },
+            },
+            VertexAttrib {
+                location: 7,
+                type_: AttribType::Float,
+                dimension: 4,
+                offset: unsafe { offset_of!(GlyphInstance, color) },
+            },
+        ]
+    }
+}
+
+/// Maps the computed pixel coverage to the alpha written by `frag.glsl`.
+///
+/// Light text on a dark background tends to look heavier than dark text on a
+/// light background, so the curve is left up to the caller.
+#[allow(unused)]
+#[derive(Copy, Clone, Debug)]
+enum CoverageCurve {
+    /// Use coverage directly as alpha.
+    Linear,
+    /// `1 - (1 - coverage)^2`, which darkens partially covered pixels.
+    Quadratic,
+    /// `coverage^(1 / gamma)`.
+    Gamma(f32),
+    /// Darkens text by up to `amount` at small sizes, fading out linearly
+    /// as the font size approaches `max_ppem` pixels per em.
+    Contrast { amount: f32, max_ppem: f32 },
+}
+
+impl CoverageCurve {
+    /// Returns the mode constant and parameters for the `COVERAGE_*`
+    /// switch in `frag.glsl`.
+    fn uniforms(self) -> (u32, [f32; 2]) {
+        match self {
+            CoverageCurve::Linear => (0, [0.0, 0.0]),
+            CoverageCurve::Quadratic => (1, [0.0, 0.0]),
+            CoverageCurve::Gamma(gamma) => (2, [gamma, 0.0]),
+            CoverageCurve::Contrast { amount, max_ppem } => (3, [amount, max_ppem]),
+        }
+    }
+
+    fn parse(value: &str) -> Option<CoverageCurve> {
+        match value {
+            "linear" => Some(CoverageCurve::Linear),
+            "quadratic" => Some(CoverageCurve::Quadratic),
+            "contrast" => Some(CoverageCurve::Contrast {
+                amount: 1.0,
+                max_ppem: 48.0,
+            }),
+            _ => {
+                // The shader raises coverage to `1 / gamma`.
+                let gamma: f32 = value.strip_prefix("gamma=")?.parse().ok()?;
+                (gamma.is_finite() && gamma > 0.0).then_some(CoverageCurve::Gamma(gamma))
+            }
+        }
+    }
+}
+
+/// Selects between grayscale antialiasing and per-channel coverage for LCD
+/// panels with the given subpixel order.
+#[allow(unused)]
+#[derive(Copy, Clone, Debug)]
+#[repr(u32)]
+enum SubpixelMode {
+    Off = 0,
+    Rgb = 1,
+    Bgr = 2,
+}
+
+/// Parameters for offsetting glyph outlines outward at small sizes so that
+/// stems do not render thinner than they would with a native rasterizer.
+#[derive(Copy, Clone, Debug)]
+struct StemDarkening {
+    /// Offset in ems applied as the pixel size approaches zero.
+    amount: f32,
+    /// Pixels per em at and above which no offset is applied.
+    max_ppem: f32,
+}
+
+impl StemDarkening {
+    fn none() -> StemDarkening {
+        StemDarkening {
+            amount: 0.0,
+            max_ppem: 1.0,
+        }
+    }
+
+    /// Returns the offset in ems at `ppem` pixels per em, rounded to whole
+    /// pixels per em so that nearby sizes share cached outlines.
+    fn offset(&self, ppem: f32) -> f32 {
+        self.amount * (1.0 - ppem.round() / self.max_ppem).clamp(0.0, 1.0)
+    }
+}
+
+impl Default for StemDarkening {
+    fn default() -> StemDarkening {
+        StemDarkening::none()
+    }
+}
+
 #[repr(C)]
 struct GlyphUniforms {
     screen_size: [f32; 2],
     transform: [f32; 16],
+    coverage_mode: u32,
+    coverage_params: [f32; 2],
+    subpixel_mode: u32,
     components: TextureId,
     points: TextureId,
 }
@@ -77,22 +312,37 @@ unsafe impl UniformFormat for GlyphUniforms {
     fn uniforms() -> Vec<Uniform> {
         vec![
             Uniform {
-                name: CStr::from_bytes_with_nul(b"uScreenSize\0").unwrap(),
+                name: c"uScreenSize",
                 type_: UniformType::Float2,
                 offset: unsafe { offset_of!(GlyphUniforms, screen_size) },
             },
             Uniform {
-                name: CStr::from_bytes_with_nul(b"uTransform\0").unwrap(),
+                name: c"uTransform",
                 type_: UniformType::Float4x4,
                 offset: unsafe { offset_of!(GlyphUniforms, transform) },
             },
             Uniform {
-                name: CStr::from_bytes_with_nul(b"uComponents\0").unwrap(),
+                name: c"uCoverageMode",
+                type_: UniformType::Uint,
+                offset: unsafe { offset_of!(GlyphUniforms, coverage_mode) },
+            },
+            Uniform {
+                name: c"uCoverageParams",
+                type_: UniformType::Float2,
+                offset: unsafe { offset_of!(GlyphUniforms, coverage_params) },
+            },
+            Uniform {
+                name: c"uSubpixelMode",
+                type_: UniformType::Uint,
+                offset: unsafe { offset_of!(GlyphUniforms, subpixel_mode) },
+            },
+            Uniform {
+                name: c"uComponents",
                 type_: UniformType::Texture,
                 offset: unsafe { offset_of!(GlyphUniforms, components) },
             },
             Uniform {
-                name: CStr::from_bytes_with_nul(b"uPoints\0").unwrap(),
+                name: c"uPoints",
                 type_: UniformType::Texture,
                 offset: unsafe { offset_of!(GlyphUniforms, points) },
             },
@@ -102,15 +352,157 @@ unsafe impl UniformFormat for GlyphUniforms {
 
 type GlyphId = u16;
 
+/// Outline adjustments that fake a bold or italic face when the family
+/// lacks one.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Synthesis {
+    /// Outward offset of each contour, in ems.
+    embolden: f32,
+    /// Horizontal shear per unit of height.
+    slant: f32,
+}
+
+impl Synthesis {
+    fn none() -> Synthesis {
+        Synthesis {
+            embolden: 0.0,
+            slant: 0.0,
+        }
+    }
+}
+
+/// Identifies a cached outline. Synthesized styles and sideways glyphs are
+/// cached separately from the regular glyph.
+#[derive(Copy, Clone, Debug)]
+struct GlyphKey {
+    id: GlyphId,
+    synthesis: Synthesis,
+    /// Rotated a quarter turn clockwise, for horizontal scripts set in
+    /// vertical text.
+    sideways: bool,
+    /// Vertical scale applied by hinting, which depends on the size.
+    y_scale: f32,
+    /// Outward offset in ems from stem darkening, which depends on the size.
+    darkening: f32,
+    /// Index of the variation instance in `Text::instances`.
+    instance: usize,
+}
+
+impl GlyphKey {
+    fn bits(&self) -> (GlyphId, usize, u32, u32, bool, u32, u32) {
+        (
+            self.id,
+            self.instance,
+            self.synthesis.embolden.to_bits(),
+            self.synthesis.slant.to_bits(),
+            self.sideways,
+            self.y_scale.to_bits(),
+            self.darkening.to_bits(),
+        )
+    }
+}
+
+impl PartialEq for GlyphKey {
+    fn eq(&self, other: &GlyphKey) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for GlyphKey {}
+
+impl std::hash::Hash for GlyphKey {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+/// Decodes the RGB channels of an sRGB-encoded color to linear values with
+/// the sRGB transfer function, leaving alpha as is.
+fn srgb_to_linear(color: [f32; 4]) -> [f32; 4] {
+    let decode = |c: f32| {
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    [
+        decode(color[0]),
+        decode(color[1]),
+        decode(color[2]),
+        color[3],
+    ]
+}
+
+/// Lines drawn alongside a run of text.
+#[derive(Copy, Clone, Debug, Default)]
+struct Decorations {
+    underline: bool,
+    strikethrough: bool,
+    overline: bool,
+    /// Interrupts the underline where it would cross descenders.
+    skip_ink: bool,
+}
+
+/// A coordinate on one of the font's variation axes, in the units of the
+/// axis as listed in `fvar`. Registered axes include `wght`, `wdth`,
+/// `opsz`, `slnt` and `ital`; fonts may also define custom ones.
+#[derive(Copy, Clone, Debug)]
+struct Variation {
+    axis: ttf_parser::Tag,
+    value: f32,
+}
+
+impl Variation {
+    fn new(axis: &[u8; 4], value: f32) -> Variation {
+        Variation {
+            axis: ttf_parser::Tag::from_bytes(axis),
+            value,
+        }
+    }
+}
+
+impl PartialEq for Variation {
+    fn eq(&self, other: &Variation) -> bool {
+        self.axis == other.axis && self.value.to_bits() == other.value.to_bits()
+    }
+}
+
+/// A span of text laid out with a single style.
+struct TextRun<'a> {
+    text: &'a str,
+    /// Axis coordinates of the font instance, with unlisted axes at their
+    /// defaults. Ignored for axes the font does not have.
+    variations: &'a [Variation],
+    synthesis: Synthesis,
+    color: [f32; 4],
+    /// `CPAL` palette used for color glyphs.
+    palette: u16,
+    decorations: Decorations,
+}
+
+impl<'a> TextRun<'a> {
+    fn new(text: &'a str) -> TextRun<'a> {
+        TextRun {
+            text,
+            variations: &[],
+            synthesis: Synthesis::none(),
+            color: [0.0, 0.0, 0.0, 1.0],
+            palette: 0,
+            decorations: Decorations::default(),
+        }
+    }
+}
+
 struct GlyphEntry {
     path: Path,
     components_range: [u32; 2],
     points_range: [u32; 2],
 }
 
-struct Text {
-    font: Font<'static>,
-    glyph_cache: HashMap<GlyphId, GlyphEntry>,
+/// Packs built paths into the components and points textures sampled by
+/// `frag.glsl`.
+struct Atlas {
     components: Vec<u16>,
     points: Vec<u16>,
     texture_width: usize,
@@ -118,13 +510,9 @@ struct Text {
     points_len: usize,
 }
 
-impl Text {
-    fn with_texture_width(texture_width: usize) -> Text {
-        let font = Font::from_data(include_bytes!("SourceSansPro-Regular.otf"), 0).unwrap();
-
-        Text {
-            font,
-            glyph_cache: HashMap::new(),
+impl Atlas {
+    fn with_texture_width(texture_width: usize) -> Atlas {
+        Atlas {
             components: Vec::new(),
             points: Vec::new(),
             texture_width,
@@ -133,193 +521,1202 @@ impl Text {
         }
     }
 
-    fn layout(&mut self, size: f32, text: &str) -> TextLayout {
-        let scale = size / self.font.units_per_em().unwrap() as f32;
-        let line_height = scale * (self.font.height() + self.font.line_gap()) as f32;
+    fn insert(&mut self, path: Path) -> GlyphEntry {
+        if self.components_len + path.components.len() > self.components.len() {
+            self.components
+                .resize(self.components.len() + self.texture_width, 0);
+        }
+        self.components[self.components_len..self.components_len + path.components.len()]
+            .clone_from_slice(&path.components);
+        let components_start = self.components_len / 2;
+        let components_end = (self.components_len + path.components.len()) / 2;
+        let components_range = [
+            components_start.try_into().unwrap(),
+            components_end.try_into().unwrap(),
+        ];
+        self.components_len += path.components.len();
+
+        if self.points_len + path.points.len() > self.points.len() {
+            self.points
+                .resize(self.points.len() + self.texture_width, 0);
+        }
+        self.points[self.points_len..self.points_len + path.points.len()]
+            .clone_from_slice(&path.points);
+        let points_start = self.points_len / 2;
+        let points_end = (self.points_len + path.points.len()) / 2;
+        let points_range = [
+            points_start.try_into().unwrap(),
+            points_end.try_into().unwrap(),
+        ];
+        self.points_len += path.points.len();
+
+        GlyphEntry {
+            path,
+            components_range,
+            points_range,
+        }
+    }
+
+    fn components_dimensions(&self) -> (usize, usize) {
+        (
+            self.texture_width,
+            self.components.len() / self.texture_width,
+        )
+    }
+
+    fn components(&self) -> &[u16] {
+        &self.components
+    }
+
+    fn points_dimensions(&self) -> (usize, usize) {
+        (self.texture_width, self.points.len() / self.texture_width)
+    }
+
+    fn points(&self) -> &[u16] {
+        &self.points
+    }
+}
+
+/// Vertical placement of a decoration line, in layout units relative to
+/// the baseline.
+#[derive(Copy, Clone)]
+struct LineMetrics {
+    /// Position of the top of the line.
+    position: f32,
+    thickness: f32,
+}
+
+/// How an overflowing line is shortened to make room for an ellipsis.
+#[derive(Copy, Clone, Debug, Default)]
+enum Truncation {
+    Start,
+    Middle,
+    #[default]
+    End,
+}
+
+/// Horizontal positions that tab characters advance to.
+#[derive(Clone, Debug)]
+struct TabStops {
+    /// Explicit stops in ems, in increasing order.
+    positions: Vec<f32>,
+    /// Spacing in ems of the stops following the explicit ones.
+    interval: f32,
+}
+
+impl TabStops {
+    /// Returns the first stop strictly after `x`, both in ems.
+    fn next(&self, x: f32) -> f32 {
+        if let Some(&stop) = self.positions.iter().find(|&&stop| stop > x) {
+            return stop;
+        }
+
+        let last = self.positions.last().copied().unwrap_or(0.0).max(0.0);
+        if self.interval <= 0.0 {
+            return x;
+        }
+        last + ((x - last) / self.interval).floor() * self.interval + self.interval
+    }
+}
+
+impl Default for TabStops {
+    fn default() -> TabStops {
+        TabStops {
+            positions: Vec::new(),
+            interval: 2.0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+enum Direction {
+    /// Lines run left to right and stack downward.
+    #[default]
+    Horizontal,
+    /// Lines run top to bottom as columns stacked right to left. Scripts
+    /// without a vertical form are set sideways.
+    Vertical,
+}
+
+/// Aligns glyph origins to the pixel grid of a known, axis-aligned
+/// screen-space transform, for flat 2D text.
+#[derive(Copy, Clone, Debug)]
+struct PixelSnapping {
+    /// Position of the layout origin in pixels, with y pointing up like
+    /// layout units.
+    origin: Vec2,
+    /// Number of pixels per layout unit.
+    pixels_per_unit: f32,
+    /// Rounds baselines (columns in vertical text) to whole pixels.
+    baselines: bool,
+    /// Quantizes glyph origins along the line to this many positions per
+    /// pixel, where 1 snaps them to whole pixels. `None` leaves them as is.
+    subpixel_positions: Option<u32>,
+}
+
+impl PixelSnapping {
+    fn none() -> PixelSnapping {
+        PixelSnapping {
+            origin: Vec2::new(0.0, 0.0),
+            pixels_per_unit: 1.0,
+            baselines: false,
+            subpixel_positions: None,
+        }
+    }
+
+    /// Rounds `value`, in layout units along an axis whose origin lies at
+    /// `origin` pixels, to the nearest of `steps` positions per pixel.
+    fn snap(&self, value: f32, origin: f32, steps: Option<u32>) -> f32 {
+        match steps {
+            Some(steps) if steps > 0 && self.pixels_per_unit > 0.0 => {
+                let steps = steps as f32;
+                let pixels = origin + value * self.pixels_per_unit;
+                ((pixels * steps).round() / steps - origin) / self.pixels_per_unit
+            }
+            _ => value,
+        }
+    }
+
+    fn baseline(&self, value: f32, origin: f32) -> f32 {
+        self.snap(value, origin, self.baselines.then_some(1))
+    }
+
+    fn position(&self, value: f32, origin: f32) -> f32 {
+        self.snap(value, origin, self.subpixel_positions)
+    }
+}
+
+impl Default for PixelSnapping {
+    fn default() -> PixelSnapping {
+        PixelSnapping::none()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct LayoutOptions {
+    direction: Direction,
+    /// Lines beyond this count are dropped and the last remaining line ends
+    /// in an ellipsis.
+    max_lines: Option<usize>,
+    /// Lines longer than this, in layout units, are truncated with an
+    /// ellipsis.
+    max_width: Option<f32>,
+    truncation: Truncation,
+    tab_stops: TabStops,
+    snapping: PixelSnapping,
+    /// Rounds the ascender, line height and x-height to whole pixels at
+    /// `snapping.pixels_per_unit`, scaling outlines vertically to match.
+    hinting: bool,
+    /// Offsets outlines outward by an amount that depends on the pixels per
+    /// em at `snapping.pixels_per_unit`.
+    stem_darkening: StemDarkening,
+}
+
+/// Vertical metrics in layout units, possibly rounded to pixels.
+struct VerticalMetrics {
+    ascender: f32,
+    line_height: f32,
+    /// Vertical scale that lands the x-height on a pixel boundary.
+    y_scale: f32,
+}
+
+enum ItemKind {
+    Glyph {
+        id: GlyphId,
+        sideways: bool,
+    },
+    /// Advances to the next tab stop, so its width depends on where it
+    /// lands on the line.
+    Tab,
+}
+
+/// A glyph placed on a line before positioning, so lines can be truncated.
+struct LineItem {
+    kind: ItemKind,
+    run: usize,
+    advance: f32,
+}
+
+/// Returns the position following `item` when it starts at `x`, where `em`
+/// is the size of an em in layout units.
+fn advance(item: &LineItem, x: f32, tab_stops: &TabStops, em: f32) -> f32 {
+    match item.kind {
+        ItemKind::Glyph { .. } => x + item.advance,
+        ItemKind::Tab => em * tab_stops.next(x / em),
+    }
+}
+
+/// Parameters shared by the steps that build and truncate lines.
+struct LineParams<'a> {
+    runs: &'a [TextRun<'a>],
+    size: f32,
+    direction: Direction,
+    tab_stops: &'a TabStops,
+    /// Variation instance of each run.
+    instances: &'a [usize],
+    /// Vertical hinting scale of each run, from its instance's x-height.
+    y_scales: &'a [f32],
+    /// Outward offset of outlines from stem darkening, in ems.
+    darkening: f32,
+}
+
+fn line_width(line: &[LineItem], tab_stops: &TabStops, em: f32) -> f32 {
+    line.iter()
+        .fold(0.0, |x, item| advance(item, x, tab_stops, em))
+}
+
+/// A stretch of a single run on a single line, recorded during layout so
+/// its decorations can be emitted afterwards.
+struct DecorationSpan {
+    run: usize,
+    start: f32,
+    end: f32,
+    baseline: f32,
+    glyphs: std::ops::Range<usize>,
+}
+
+/// Returns whether `c` stays upright in vertical text. This approximates
+/// the Unicode `Vertical_Orientation` property by block.
+fn is_upright(c: char) -> bool {
+    matches!(c as u32,
+        0x1100..=0x11FF // Hangul Jamo
+        | 0x2E80..=0x2FDF // CJK radicals and Kangxi radicals
+        | 0x2FF0..=0x303F // Ideographic description and CJK symbols
+        | 0x3040..=0x31FF // Kana, Bopomofo and Hangul compatibility Jamo
+        | 0x3200..=0x4DBF // Enclosed CJK, compatibility and extension A
+        | 0x4E00..=0x9FFF // CJK unified ideographs
+        | 0xA000..=0xA4CF // Yi
+        | 0xAC00..=0xD7AF // Hangul syllables
+        | 0xF900..=0xFAFF // CJK compatibility ideographs
+        | 0xFE10..=0xFE1F // Vertical forms
+        | 0xFE30..=0xFE4F // CJK compatibility forms
+        | 0xFF00..=0xFF60 // Fullwidth forms
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFF // Supplementary ideographic planes
+    )
+}
+
+struct Text {
+    font: Face<'static>,
+    /// Variation instances referenced by glyph keys. The first is the
+    /// default instance.
+    instances: Vec<Vec<Variation>>,
+    /// Instance the font's coordinates are currently set to.
+    instance: usize,
+    /// Layered color glyphs, if the font has a `COLR` table.
+    colors: Option<ColorGlyphs<'static>>,
+    glyph_cache: HashMap<GlyphKey, GlyphEntry>,
+    rect: Option<GlyphEntry>,
+    atlas: Atlas,
+    /// Whether sRGB-encoded colors from fonts and documents are decoded to
+    /// linear values, for framebuffers that encode them again on write.
+    linear_colors: bool,
+}
+
+impl Text {
+    fn with_texture_width(texture_width: usize) -> Text {
+        Text::with_font(include_bytes!("SourceSansPro-Regular.otf"), texture_width).unwrap()
+    }
+
+    /// Loads the first face in `data`, returning `None` if it can't be parsed.
+    fn with_font(data: &'static [u8], texture_width: usize) -> Option<Text> {
+        let font = Face::from_slice(data, 0).ok()?;
+        let colors = font
+            .table_data(ttf_parser::Tag::from_bytes(b"COLR"))
+            .and_then(|colr| {
+                let cpal = font.table_data(ttf_parser::Tag::from_bytes(b"CPAL"));
+                ColorGlyphs::parse(colr, cpal)
+            });
+
+        Some(Text {
+            font,
+            instances: vec![Vec::new()],
+            instance: 0,
+            colors,
+            glyph_cache: HashMap::new(),
+            rect: None,
+            atlas: Atlas::with_texture_width(texture_width),
+            linear_colors: false,
+        })
+    }
+
+    /// Sets whether sRGB-encoded colors, from `CPAL` palettes and SVG
+    /// documents, are decoded to linear values, which should match whether
+    /// `FRAMEBUFFER_SRGB` is enabled.
+    fn set_linear_colors(&mut self, linear_colors: bool) {
+        self.linear_colors = linear_colors;
+    }
+
+    /// Returns `color`, decoded to linear values if `linear_colors` is set.
+    fn decode_color(&self, color: [f32; 4]) -> [f32; 4] {
+        if self.linear_colors {
+            srgb_to_linear(color)
+        } else {
+            color
+        }
+    }
+
+    /// Returns the number of palettes available for color glyphs.
+    fn palettes(&self) -> u16 {
+        self.colors.as_ref().map_or(0, |colors| colors.palettes())
+    }
+
+    /// Returns the range of a variation axis, or `None` if the font lacks it.
+    fn variation_axis(&self, axis: &[u8; 4]) -> Option<ttf_parser::VariationAxis> {
+        let tag = ttf_parser::Tag::from_bytes(axis);
+        self.font
+            .variation_axes()
+            .into_iter()
+            .find(|variation_axis| variation_axis.tag == tag)
+    }
+
+    /// Returns the index of the instance with the given coordinates, adding
+    /// it if it's new. Coordinates are matched regardless of order, and a
+    /// later coordinate on an axis overrides earlier ones.
+    fn instance(&mut self, variations: &[Variation]) -> usize {
+        let mut variations: Vec<Variation> = variations.iter().rev().copied().collect();
+        variations.sort_by_key(|variation| variation.axis);
+        variations.dedup_by_key(|variation| variation.axis);
+
+        if let Some(index) = self
+            .instances
+            .iter()
+            .position(|instance| *instance == variations)
+        {
+            return index;
+        }
+
+        self.instances.push(variations);
+        self.instances.len() - 1
+    }
+
+    /// Sets the font's coordinates to those of `instance`, so outlines and
+    /// metrics come from that instance.
+    fn select_instance(&mut self, instance: usize) {
+        if self.instance == instance {
+            return;
+        }
+
+        for axis in self.font.variation_axes() {
+            let _ = self.font.set_variation(axis.tag, axis.def_value);
+        }
+        for variation in self.instances[instance].iter() {
+            let _ = self.font.set_variation(variation.axis, variation.value);
+        }
+        self.instance = instance;
+    }
+
+    fn layout(&mut self, size: f32, runs: &[TextRun], options: &LayoutOptions) -> TextLayout {
+        let scale = size / self.font.units_per_em() as f32;
+
+        let instances: Vec<usize> = runs
+            .iter()
+            .map(|run| self.instance(run.variations))
+            .collect();
+
+        // Each run is hinted for its own instance, and lines are spaced to fit
+        // the tallest of them.
+        let mut metrics = Vec::with_capacity(runs.len());
+        for &instance in instances.iter() {
+            self.select_instance(instance);
+            metrics.push(self.vertical_metrics(size, options));
+        }
+        if metrics.is_empty() {
+            self.select_instance(0);
+            metrics.push(self.vertical_metrics(size, options));
+        }
+        let ascender = metrics.iter().map(|m| m.ascender).fold(f32::MIN, f32::max);
+        let line_height = metrics
+            .iter()
+            .map(|m| m.line_height)
+            .fold(f32::MIN, f32::max);
+        let y_scales: Vec<f32> = metrics.iter().map(|m| m.y_scale).collect();
+
+        let tab_stops = &options.tab_stops;
+        let params = LineParams {
+            runs,
+            size,
+            direction: options.direction,
+            tab_stops,
+            instances: &instances,
+            y_scales: &y_scales,
+            darkening: options
+                .stem_darkening
+                .offset(size * options.snapping.pixels_per_unit),
+        };
+
+        let mut lines: Vec<Vec<LineItem>> = vec![Vec::new()];
+        let mut after_cr = false;
+        for (run_index, run) in runs.iter().enumerate() {
+            for c in run.text.chars() {
+                let after = std::mem::replace(&mut after_cr, c == '\r');
+                match c {
+                    // Treat CRLF as a single line break.
+                    '\n' if after => {}
+                    '\n' | '\r' => lines.push(Vec::new()),
+                    '\t' => lines.last_mut().unwrap().push(LineItem {
+                        kind: ItemKind::Tab,
+                        run: run_index,
+                        advance: 0.0,
+                    }),
+                    // Zero-width space, non-joiner, joiner, word joiner and
+                    // byte order mark.
+                    '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}' => {}
+                    _ => {
+                        // Fall back to a regular space for non-breaking spaces
+                        // the font does not cover.
+                        let item = self.line_item(c, run_index, &params).or_else(|| {
+                            matches!(c, '\u{00A0}' | '\u{202F}')
+                                .then(|| self.line_item(' ', run_index, &params))
+                                .flatten()
+                        });
+                        lines.last_mut().unwrap().extend(item);
+                    }
+                }
+            }
+        }
+
+        let mut truncated = false;
+        let max_width = options.max_width.unwrap_or(f32::INFINITY);
+
+        if let Some(max_lines) = options.max_lines {
+            if lines.len() > max_lines {
+                lines.truncate(max_lines);
+                truncated = true;
+
+                // The omitted lines follow the last one, so it always ends in
+                // an ellipsis regardless of the truncation mode.
+                if let Some(line) = lines.last_mut() {
+                    self.ellipsize(line, Truncation::End, max_width, &params);
+                }
+            }
+        }
+
+        for line in lines.iter_mut() {
+            if line_width(line, tab_stops, size) > max_width {
+                self.ellipsize(line, options.truncation, max_width, &params);
+                truncated = true;
+            }
+        }
+
+        if options.direction == Direction::Vertical {
+            return self.position_vertical(&lines, &params, &options.snapping, truncated);
+        }
 
         let mut glyphs = Vec::new();
+        let mut spans: Vec<DecorationSpan> = Vec::new();
         let mut width: f32 = 0.0;
-        let mut height: f32 = line_height;
 
-        let mut pos = Vec2::new(0.0, scale * self.font.ascender() as f32);
+        // The pen advances unsnapped so that rounding errors do not
+        // accumulate along the line; only glyph origins are snapped.
+        let snapping = &options.snapping;
+        let mut pos = Vec2::new(0.0, ascender);
+
+        for line in lines.iter() {
+            pos.x = 0.0;
+            let baseline = snapping.baseline(pos.y, snapping.origin.y);
+
+            let mut span: Option<DecorationSpan> = None;
+            for item in line {
+                let run = &runs[item.run];
+
+                if span.as_ref().is_none_or(|span| span.run != item.run) {
+                    spans.extend(span.take());
+                    span = Some(DecorationSpan {
+                        run: item.run,
+                        start: pos.x,
+                        end: pos.x,
+                        baseline,
+                        glyphs: glyphs.len()..glyphs.len(),
+                    });
+                }
 
-        for c in text.chars() {
-            if c == '\n' {
-                pos.x = 0.0;
-                pos.y -= line_height;
-                height += line_height;
-            } else if let Ok(glyph_id) = self.font.glyph_index(c) {
-                glyphs.push(Glyph {
-                    id: glyph_id.0,
-                    pos,
-                });
+                if let ItemKind::Glyph { id, sideways } = item.kind {
+                    let key = GlyphKey {
+                        id,
+                        synthesis: run.synthesis,
+                        sideways,
+                        y_scale: params.y_scales[item.run],
+                        darkening: params.darkening,
+                        instance: params.instances[item.run],
+                    };
+                    // Emboldening grows the outline to the left as well, so
+                    // it's moved into the wider advance.
+                    let x = pos.x + run.synthesis.embolden * size;
+                    self.push_glyph(
+                        &mut glyphs,
+                        key,
+                        run,
+                        Vec2::new(snapping.position(x, snapping.origin.x), baseline),
+                    );
+                }
+                pos.x = advance(item, pos.x, tab_stops, size);
 
-                pos.x += scale * self.font.glyph_hor_metrics(glyph_id).unwrap().advance as f32;
-                width = width.max(pos.x);
+                let span = span.as_mut().unwrap();
+                span.end = pos.x;
+                span.glyphs.end = glyphs.len();
             }
+            spans.extend(span);
+
+            width = width.max(pos.x);
+            pos.y -= line_height;
+        }
+
+        let mut decorations = Vec::new();
+        for span in spans {
+            self.decorate(&runs[span.run], &span, &glyphs, scale, &mut decorations);
         }
 
         TextLayout {
             glyphs,
+            decorations,
+            scale,
+            width,
+            height: line_height * lines.len() as f32,
+            truncated,
+        }
+    }
+
+    /// Places lines as columns running downward from `y = 0`, with the first
+    /// column on the right. Decorations are not drawn in vertical text.
+    fn position_vertical(
+        &mut self,
+        lines: &[Vec<LineItem>],
+        params: &LineParams,
+        snapping: &PixelSnapping,
+        truncated: bool,
+    ) -> TextLayout {
+        let units_per_em = self.font.units_per_em() as f32;
+        let scale = params.size / units_per_em;
+
+        let column_width = match (self.font.vertical_height(), self.font.vertical_line_gap()) {
+            (Some(height), Some(line_gap)) => scale * (height + line_gap) as f32,
+            _ => scale * (self.font.height() + self.font.line_gap()) as f32,
+        };
+        let width = column_width * lines.len() as f32;
+
+        // Sideways glyphs are centered on the column by their em box.
+        let sideways_offset = 0.5 * scale * (self.font.ascender() + self.font.descender()) as f32;
+
+        let mut glyphs = Vec::new();
+        let mut height: f32 = 0.0;
+
+        for (i, line) in lines.iter().enumerate() {
+            let center = width - (i as f32 + 0.5) * column_width;
+
+            let mut y = 0.0;
+            for item in line {
+                let run = &params.runs[item.run];
+
+                if let ItemKind::Glyph { id, sideways } = item.kind {
+                    self.select_instance(params.instances[item.run]);
+                    // Emboldening grows the outline upward as well, so it's
+                    // moved down into the taller advance.
+                    let y = y + run.synthesis.embolden * params.size;
+                    let pos = if sideways {
+                        Vec2::new(center - sideways_offset, -y)
+                    } else {
+                        let glyph_id = ttf_parser::GlyphId(id);
+                        let advance = self.font.glyph_hor_advance(glyph_id).unwrap_or(0);
+                        let origin = self.vertical_origin(glyph_id);
+                        Vec2::new(
+                            center - 0.5 * scale * advance as f32,
+                            -y - scale * origin as f32,
+                        )
+                    };
+                    let pos = Vec2::new(
+                        snapping.baseline(pos.x, snapping.origin.x),
+                        snapping.position(pos.y, snapping.origin.y),
+                    );
+
+                    let key = GlyphKey {
+                        id,
+                        synthesis: run.synthesis,
+                        sideways,
+                        // Sideways glyphs aren't hinted, so they share one
+                        // cache entry across sizes.
+                        y_scale: if sideways {
+                            1.0
+                        } else {
+                            params.y_scales[item.run]
+                        },
+                        darkening: params.darkening,
+                        instance: params.instances[item.run],
+                    };
+                    self.push_glyph(&mut glyphs, key, run, pos);
+                }
+                y = advance(item, y, params.tab_stops, params.size);
+            }
+
+            height = height.max(y);
+        }
+
+        TextLayout {
+            glyphs,
+            decorations: Vec::new(),
             scale,
             width,
             height,
+            truncated,
         }
     }
 
-    fn mesh(&mut self, layout: &TextLayout) -> Mesh<GlyphVertex> {
-        let mut vertices = Vec::new();
-        let mut indices = Vec::new();
+    /// Returns the ascender, line height and x-height scale at `size`,
+    /// rounded to whole pixels if `options.hinting` is set.
+    fn vertical_metrics(&self, size: f32, options: &LayoutOptions) -> VerticalMetrics {
+        let scale = size / self.font.units_per_em() as f32;
+        let ascender = scale * self.font.ascender() as f32;
+        let line_height = scale * (self.font.height() + self.font.line_gap()) as f32;
 
-        for glyph in layout.glyphs.iter() {
-            let glyph_entry = self.glyph_cache.entry(glyph.id).or_insert_with(|| {
-                use ttf_parser::OutlineBuilder;
+        let pixels_per_unit = options.snapping.pixels_per_unit;
+        if !options.hinting || pixels_per_unit <= 0.0 {
+            return VerticalMetrics {
+                ascender,
+                line_height,
+                y_scale: 1.0,
+            };
+        }
+
+        let round = |value: f32| (value * pixels_per_unit).round().max(1.0) / pixels_per_unit;
+
+        // Fall back to the height of 'x' for fonts without an OS/2 x-height.
+        let x_height = self
+            .font
+            .x_height()
+            .filter(|&x_height| x_height > 0)
+            .or_else(|| {
+                let glyph_id = self.font.glyph_index('x')?;
+                Some(self.font.glyph_bounding_box(glyph_id)?.y_max)
+            });
+        let y_scale = match x_height {
+            Some(x_height) if x_height > 0 => {
+                let x_height = scale * x_height as f32;
+                round(x_height) / x_height
+            }
+            _ => 1.0,
+        };
 
-                struct Builder {
-                    path: PathBuilder,
+        VerticalMetrics {
+            ascender: round(ascender),
+            line_height: round(line_height),
+            y_scale,
+        }
+    }
+
+    /// Adds a glyph at `pos`, expanded into its layers if it's a color glyph.
+    fn push_glyph(&self, glyphs: &mut Vec<Glyph>, key: GlyphKey, run: &TextRun, pos: Vec2) {
+        let layers = self
+            .colors
+            .as_ref()
+            .and_then(|colors| colors.layers(key.id));
+        let layers = match layers {
+            Some(layers) => layers,
+            None => {
+                glyphs.push(Glyph {
+                    key,
+                    color: run.color,
+                    pos,
+                });
+                return;
+            }
+        };
+
+        let colors = self.colors.as_ref().unwrap();
+        for layer in layers {
+            // Missing palette entries fall back to the text color.
+            let mut color = layer
+                .palette_index
+                .and_then(|index| colors.color(run.palette, index))
+                .map(|color| self.decode_color(color))
+                .unwrap_or(run.color);
+            if layer.palette_index.is_some() {
+                color[3] *= run.color[3];
+            }
+
+            glyphs.push(Glyph {
+                key: GlyphKey {
+                    id: layer.glyph,
+                    ..key
+                },
+                color,
+                pos,
+            });
+        }
+    }
+
+    /// Returns the height above the baseline of the point that sits on the
+    /// pen position in vertical text, in font units.
+    fn vertical_origin(&self, glyph_id: ttf_parser::GlyphId) -> i16 {
+        if let Some(origin) = self.font.glyph_y_origin(glyph_id) {
+            return origin;
+        }
+
+        // Without a `VORG` table, derive the origin from the top side bearing.
+        let top_side_bearing = self.font.glyph_ver_side_bearing(glyph_id);
+        let bbox = self.font.glyph_bounding_box(glyph_id);
+        match (top_side_bearing, bbox) {
+            (Some(top_side_bearing), Some(bbox)) => bbox.y_max + top_side_bearing,
+            _ => self.font.ascender(),
+        }
+    }
+
+    fn line_item(&mut self, c: char, run_index: usize, params: &LineParams) -> Option<LineItem> {
+        self.select_instance(params.instances[run_index]);
+
+        let run = &params.runs[run_index];
+        let scale = params.size / self.font.units_per_em() as f32;
+        let mut glyph_id = self.font.glyph_index(c)?;
+
+        // Emboldening grows the outline on both sides.
+        let extra_advance = 2.0 * run.synthesis.embolden * params.size;
+
+        let sideways = params.direction == Direction::Vertical && !is_upright(c);
+        let advance = if params.direction == Direction::Vertical && !sideways {
+            glyph_id = self.vertical_substitute(glyph_id);
+            self.font
+                .glyph_ver_advance(glyph_id)
+                .unwrap_or(self.font.units_per_em())
+        } else {
+            self.font.glyph_hor_advance(glyph_id)?
+        };
+
+        Some(LineItem {
+            kind: ItemKind::Glyph {
+                id: glyph_id.0,
+                sideways,
+            },
+            run: run_index,
+            advance: scale * advance as f32 + extra_advance,
+        })
+    }
+
+    /// Applies the single substitutions of the `vrt2` feature, or of `vert`
+    /// if the font lacks it, to get the vertical form of a glyph.
+    fn vertical_substitute(&self, glyph_id: ttf_parser::GlyphId) -> ttf_parser::GlyphId {
+        use ttf_parser::gsub::SubstitutionSubtable;
+
+        let gsub = match self.font.tables().gsub {
+            Some(gsub) => gsub,
+            None => return glyph_id,
+        };
+
+        let feature = gsub
+            .features
+            .find(ttf_parser::Tag::from_bytes(b"vrt2"))
+            .or_else(|| gsub.features.find(ttf_parser::Tag::from_bytes(b"vert")));
+        let feature = match feature {
+            Some(feature) => feature,
+            None => return glyph_id,
+        };
+
+        let mut glyph_id = glyph_id;
+        for lookup_index in feature.lookup_indices {
+            let lookup = match gsub.lookups.get(lookup_index) {
+                Some(lookup) => lookup,
+                None => continue,
+            };
+
+            for subtable in lookup.subtables.into_iter::<SubstitutionSubtable>() {
+                if let SubstitutionSubtable::Single(single) = subtable {
+                    use ttf_parser::gsub::SingleSubstitution;
+
+                    let index = match single.coverage().get(glyph_id) {
+                        Some(index) => index,
+                        None => continue,
+                    };
+                    glyph_id = match single {
+                        SingleSubstitution::Format1 { delta, .. } => {
+                            ttf_parser::GlyphId((glyph_id.0 as i32 + delta as i32) as u16)
+                        }
+                        SingleSubstitution::Format2 { substitutes, .. } => {
+                            match substitutes.get(index) {
+                                Some(substitute) => substitute,
+                                None => continue,
+                            }
+                        }
+                    };
+                    break;
                 }
-                impl OutlineBuilder for Builder {
-                    fn move_to(&mut self, x: f32, y: f32) {
-                        self.path.move_to(Vec2::new(x, y));
-                    }
-                    fn line_to(&mut self, x: f32, y: f32) {
-                        self.path.line_to(Vec2::new(x, y));
-                    }
-                    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
-                        self.path.quadratic_to(Vec2::new(x1, y1), Vec2::new(x, y));
-                    }
-                    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
-                        self.path
-                            .cubic_to(Vec2::new(x1, y1), Vec2::new(x2, y2), Vec2::new(x, y));
-                    }
-                    fn close(&mut self) {
-                        self.path.close();
+            }
+        }
+
+        glyph_id
+    }
+
+    /// Removes glyphs from `line` according to `truncation` until it fits in
+    /// `max_width` along with an ellipsis, then inserts the ellipsis.
+    fn ellipsize(
+        &mut self,
+        line: &mut Vec<LineItem>,
+        truncation: Truncation,
+        max_width: f32,
+        params: &LineParams,
+    ) {
+        let runs = params.runs;
+        let (size, tab_stops) = (params.size, params.tab_stops);
+
+        // The ellipsis takes the style of the last glyph it replaces, so it's
+        // measured again whenever that changes.
+        let mut run = match truncation {
+            Truncation::Start => line.first(),
+            Truncation::Middle => line.get(line.len() / 2),
+            Truncation::End => line.last(),
+        }
+        .map_or(runs.len().saturating_sub(1), |item| item.run);
+
+        let mut ellipsis = |run: usize| -> Vec<LineItem> {
+            if runs.is_empty() {
+                return Vec::new();
+            }
+            if let Some(item) = self.line_item('\u{2026}', run, params) {
+                return vec![item];
+            }
+            (0..3)
+                .filter_map(|_| self.line_item('.', run, params))
+                .collect()
+        };
+
+        let mut at = match truncation {
+            Truncation::Start => 0,
+            Truncation::Middle => line.len() / 2,
+            Truncation::End => line.len(),
+        };
+        loop {
+            let items = ellipsis(run);
+            let width = line_width(line, tab_stops, size) + line_width(&items, tab_stops, size);
+            if line.is_empty() || width <= max_width {
+                let at = at.min(line.len());
+                line.splice(at..at, items);
+                return;
+            }
+
+            let index = match truncation {
+                Truncation::Start => 0,
+                Truncation::Middle => line.len() / 2,
+                Truncation::End => line.len() - 1,
+            };
+            run = line.remove(index).run;
+            at = index;
+        }
+    }
+
+    fn decorate(
+        &mut self,
+        run: &TextRun,
+        span: &DecorationSpan,
+        glyphs: &[Glyph],
+        scale: f32,
+        decorations: &mut Vec<Decoration>,
+    ) {
+        if span.end <= span.start {
+            return;
+        }
+
+        let units_per_em = self.font.units_per_em() as f32;
+        let underline = match self.font.underline_metrics() {
+            Some(metrics) => LineMetrics {
+                position: scale * metrics.position as f32,
+                thickness: scale * metrics.thickness as f32,
+            },
+            None => LineMetrics {
+                position: -0.1 * scale * units_per_em,
+                thickness: 0.05 * scale * units_per_em,
+            },
+        };
+
+        let mut lines = Vec::new();
+        if run.decorations.underline {
+            lines.push((underline, run.decorations.skip_ink));
+        }
+        if run.decorations.strikethrough {
+            let strikeout = match self.font.strikeout_metrics() {
+                Some(metrics) => LineMetrics {
+                    position: scale * metrics.position as f32,
+                    thickness: scale * metrics.thickness as f32,
+                },
+                None => LineMetrics {
+                    position: 0.5 * scale * self.font.x_height().unwrap_or(0) as f32
+                        + 0.5 * underline.thickness,
+                    thickness: underline.thickness,
+                },
+            };
+            lines.push((strikeout, false));
+        }
+        if run.decorations.overline {
+            let overline = LineMetrics {
+                position: scale * self.font.ascender() as f32,
+                thickness: underline.thickness,
+            };
+            lines.push((overline, false));
+        }
+
+        for (metrics, skip_ink) in lines {
+            let top = span.baseline + metrics.position;
+            let bottom = top - metrics.thickness;
+
+            let mut gaps = Vec::new();
+            if skip_ink {
+                // Leave a gap of one line thickness on either side of ink.
+                let gap = metrics.thickness;
+                for glyph in glyphs[span.glyphs.clone()].iter() {
+                    let entry = self.glyph_entry(glyph.key);
+                    let extent = entry.path.horizontal_extent(
+                        (bottom - gap - glyph.pos.y) / scale,
+                        (top + gap - glyph.pos.y) / scale,
+                    );
+                    if let Some((min, max)) = extent {
+                        gaps.push((
+                            glyph.pos.x + scale * min - gap,
+                            glyph.pos.x + scale * max + gap,
+                        ));
                     }
                 }
+                gaps.sort_by(|a, b| a.0.total_cmp(&b.0));
+            }
 
-                let mut builder = Builder {
-                    path: PathBuilder::new(),
-                };
-                let _ = self
-                    .font
-                    .outline_glyph(ttf_parser::GlyphId(glyph.id), &mut builder);
+            let mut start = span.start;
+            for (gap_start, gap_end) in gaps
+                .into_iter()
+                .chain(std::iter::once((span.end, span.end)))
+            {
+                let end = gap_start.min(span.end);
+                if end > start {
+                    decorations.push(Decoration {
+                        min: Vec2::new(start, bottom),
+                        max: Vec2::new(end, top),
+                        color: run.color,
+                    });
+                }
+                start = start.max(gap_end);
+            }
+        }
+    }
 
-                let path = builder.path.build();
+    fn glyph_entry(&mut self, key: GlyphKey) -> &GlyphEntry {
+        let GlyphKey {
+            id,
+            synthesis,
+            sideways,
+            y_scale,
+            darkening,
+            instance,
+        } = key;
+        if !self.glyph_cache.contains_key(&key) {
+            self.select_instance(instance);
+        }
+        self.glyph_cache.entry(key).or_insert_with(|| {
+            use ttf_parser::OutlineBuilder;
 
-                if self.components_len + path.components.len() > self.components.len() {
-                    self.components
-                        .resize(self.components.len() + self.texture_width, 0);
+            struct Builder {
+                path: PathBuilder,
+            }
+            impl OutlineBuilder for Builder {
+                fn move_to(&mut self, x: f32, y: f32) {
+                    self.path.move_to(Vec2::new(x, y));
+                }
+                fn line_to(&mut self, x: f32, y: f32) {
+                    self.path.line_to(Vec2::new(x, y));
                 }
-                self.components[self.components_len..self.components_len + path.components.len()]
-                    .clone_from_slice(&path.components);
-                let components_start = self.components_len / 2;
-                let components_end = (self.components_len + path.components.len()) / 2;
-                let components_range = [
-                    components_start.try_into().unwrap(),
-                    components_end.try_into().unwrap(),
-                ];
-                self.components_len += path.components.len();
-
-                if self.points_len + path.points.len() > self.points.len() {
-                    self.points
-                        .resize(self.points.len() + self.texture_width, 0);
+                fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+                    self.path.quadratic_to(Vec2::new(x1, y1), Vec2::new(x, y));
                 }
-                self.points[self.points_len..self.points_len + path.points.len()]
-                    .clone_from_slice(&path.points);
-                let points_start = self.points_len / 2;
-                let points_end = (self.points_len + path.points.len()) / 2;
-                let points_range = [
-                    points_start.try_into().unwrap(),
-                    points_end.try_into().unwrap(),
-                ];
-                self.points_len += path.points.len();
-
-                GlyphEntry {
-                    path,
-                    components_range,
-                    points_range,
+                fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+                    self.path
+                        .cubic_to(Vec2::new(x1, y1), Vec2::new(x2, y2), Vec2::new(x, y));
                 }
+                fn close(&mut self) {
+                    self.path.close();
+                }
+            }
+
+            let mut builder = Builder {
+                path: PathBuilder::new(),
+            };
+            builder.path.tolerance(Tolerance::Em {
+                fraction: CUBIC_TOLERANCE,
+                units_per_em: self.font.units_per_em() as f32,
             });
+            let _ = self
+                .font
+                .outline_glyph(ttf_parser::GlyphId(id), &mut builder);
+
+            // The x-height of a sideways glyph runs across the column once
+            // rotated, so the vertical hinting scale doesn't apply to it.
+            if y_scale != 1.0 && !sideways {
+                builder.path.stretch_vertically(y_scale);
+            }
+            // Stem darkening offsets the outline like emboldening, but leaves
+            // the advance alone.
+            let offset = synthesis.embolden + darkening;
+            if offset != 0.0 {
+                let units_per_em = self.font.units_per_em() as f32;
+                builder.path.embolden(offset * units_per_em);
+            }
+            if synthesis.slant != 0.0 {
+                builder.path.slant(synthesis.slant);
+            }
+            if sideways {
+                builder.path.rotate_sideways();
+            }
+
+            self.atlas.insert(builder.path.build())
+        })
+    }
+
+    /// Returns the entry for a unit square, used to draw decorations.
+    fn rect_entry(&mut self) -> &GlyphEntry {
+        let atlas = &mut self.atlas;
+        self.rect.get_or_insert_with(|| {
+            let mut builder = PathBuilder::new();
+            builder
+                .move_to(Vec2::new(0.0, 0.0))
+                .line_to(Vec2::new(1.0, 0.0))
+                .line_to(Vec2::new(1.0, 1.0))
+                .line_to(Vec2::new(0.0, 1.0))
+                .close();
+            atlas.insert(builder.build())
+        })
+    }
+
+    fn mesh(&mut self, layout: &TextLayout) -> Mesh<GlyphVertex> {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        let units_per_em = self.font.units_per_em() as f32;
+        let em = layout.scale * units_per_em;
+
+        for glyph in layout.glyphs.iter() {
+            let glyph_entry = self.glyph_entry(glyph.key);
+            let path = &glyph_entry.path;
+            let size = path.max - path.min;
 
             let base: u16 = vertices.len().try_into().unwrap();
-            vertices.extend_from_slice(&[
-                GlyphVertex {
-                    pos: [
-                        glyph.pos.x + layout.scale * glyph_entry.path.min.x,
-                        glyph.pos.y + layout.scale * glyph_entry.path.min.y,
-                        0.0,
-                    ],
-                    uv: [0.0, 0.0],
-                    components_range: glyph_entry.components_range,
-                    points_range: glyph_entry.points_range,
-                },
-                GlyphVertex {
-                    pos: [
-                        glyph.pos.x + layout.scale * glyph_entry.path.max.x,
-                        glyph.pos.y + layout.scale * glyph_entry.path.min.y,
-                        0.0,
-                    ],
-                    uv: [1.0, 0.0],
-                    components_range: glyph_entry.components_range,
-                    points_range: glyph_entry.points_range,
-                },
-                GlyphVertex {
-                    pos: [
-                        glyph.pos.x + layout.scale * glyph_entry.path.max.x,
-                        glyph.pos.y + layout.scale * glyph_entry.path.max.y,
-                        0.0,
-                    ],
-                    uv: [1.0, 1.0],
-                    components_range: glyph_entry.components_range,
-                    points_range: glyph_entry.points_range,
-                },
-                GlyphVertex {
-                    pos: [
-                        glyph.pos.x + layout.scale * glyph_entry.path.min.x,
-                        glyph.pos.y + layout.scale * glyph_entry.path.max.y,
-                        0.0,
-                    ],
-                    uv: [0.0, 1.0],
-                    components_range: glyph_entry.components_range,
-                    points_range: glyph_entry.points_range,
-                },
-            ]);
+            vertices.extend_from_slice(&GlyphVertex::quad(
+                glyph_entry,
+                glyph.pos + layout.scale * path.min,
+                glyph.pos + layout.scale * path.max,
+                [size.x / units_per_em, size.y / units_per_em],
+                glyph.color,
+            ));
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+
+        let rect_entry = self.rect_entry();
+        for decoration in layout.decorations.iter() {
+            let size = decoration.max - decoration.min;
+
+            let base: u16 = vertices.len().try_into().unwrap();
+            vertices.extend_from_slice(&GlyphVertex::quad(
+                rect_entry,
+                decoration.min,
+                decoration.max,
+                [size.x / em, size.y / em],
+                decoration.color,
+            ));
             indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
         }
 
         Mesh::new(&vertices, &indices)
     }
 
-    fn components_dimensions(&self) -> (usize, usize) {
-        (
-            self.texture_width,
-            self.components.len() / self.texture_width,
-        )
-    }
+    fn instances(&mut self, layout: &TextLayout) -> Mesh<GlyphInstance> {
+        let mut instances = Vec::with_capacity(layout.glyphs.len() + layout.decorations.len());
 
-    fn components(&self) -> &[u16] {
-        &self.components
+        let units_per_em = self.font.units_per_em() as f32;
+        let em = layout.scale * units_per_em;
+
+        for glyph in layout.glyphs.iter() {
+            let glyph_entry = self.glyph_entry(glyph.key);
+            let size = glyph_entry.path.max - glyph_entry.path.min;
+
+            instances.push(GlyphInstance {
+                origin: [glyph.pos.x, glyph.pos.y, 0.0],
+                scale: layout.scale,
+                bbox: [
+                    glyph_entry.path.min.x,
+                    glyph_entry.path.min.y,
+                    glyph_entry.path.max.x,
+                    glyph_entry.path.max.y,
+                ],
+                components_range: glyph_entry.components_range,
+                points_range: glyph_entry.points_range,
+                fill_rule: glyph_entry.path.fill_rule as u32,
+                extent: [size.x / units_per_em, size.y / units_per_em],
+                color: glyph.color,
+            });
+        }
+
+        // Decorations are placed directly in layout units.
+        let rect_entry = self.rect_entry();
+        for decoration in layout.decorations.iter() {
+            let size = decoration.max - decoration.min;
+
+            instances.push(GlyphInstance {
+                origin: [decoration.min.x, decoration.min.y, 0.0],
+                scale: 1.0,
+                bbox: [0.0, 0.0, size.x, size.y],
+                components_range: rect_entry.components_range,
+                points_range: rect_entry.points_range,
+                fill_rule: rect_entry.path.fill_rule as u32,
+                extent: [size.x / em, size.y / em],
+                color: decoration.color,
+            });
+        }
+
+        Mesh::instanced(&instances, &[0, 1, 2, 0, 2, 3])
     }
 
-    fn points_dimensions(&self) -> (usize, usize) {
-        (self.texture_width, self.points.len() / self.texture_width)
+    /// Packs `shapes` into the atlas and builds quads drawing them offset by
+    /// `origin` in layout units.
+    fn shape_mesh(&mut self, shapes: Vec<Shape>, origin: Vec2) -> Mesh<GlyphVertex> {
+        let mut vertices = Vec::new();
+        let mut indices = Vec::new();
+
+        for shape in shapes {
+            let color = self.decode_color(shape.color);
+            let entry = self.atlas.insert(shape.path);
+            let (min, max) = (entry.path.min, entry.path.max);
+            let size = max - min;
+
+            let base: u16 = vertices.len().try_into().unwrap();
+            vertices.extend_from_slice(&GlyphVertex::quad(
+                &entry,
+                origin + min,
+                origin + max,
+                [size.x / SHAPE_EM, size.y / SHAPE_EM],
+                color,
+            ));
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        }
+
+        Mesh::new(&vertices, &indices)
     }
 
-    fn points(&self) -> &[u16] {
-        &self.points
+    fn atlas(&self) -> &Atlas {
+        &self.atlas
     }
 }
 
 struct Glyph {
-    id: GlyphId,
+    key: GlyphKey,
+    color: [f32; 4],
     pos: Vec2,
 }
 
+/// A solid rectangle drawn alongside text, in layout units.
+struct Decoration {
+    min: Vec2,
+    max: Vec2,
+    color: [f32; 4],
+}
+
 struct TextLayout {
     glyphs: Vec<Glyph>,
+    decorations: Vec<Decoration>,
     scale: f32,
     width: f32,
     height: f32,
+    truncated: bool,
 }
 
 impl TextLayout {
@@ -330,6 +1727,12 @@ impl TextLayout {
     fn height(&self) -> f32 {
         self.height
     }
+
+    /// Returns whether lines or glyphs were dropped to satisfy the maximum
+    /// line count or width.
+    fn truncated(&self) -> bool {
+        self.truncated
+    }
 }
 
 const SCREEN_WIDTH: f32 = 800.0;
@@ -358,21 +1761,78 @@ feugiat in, orci. In hac habitasse platea dictumst.";
 
 const SIZE: f32 = 18.0;
 
+/// Offset in pixels of flat 2D text from the top left corner.
+const FLAT_MARGIN: f32 = 16.0;
+
 const TEXTURE_WIDTH: usize = 4096;
 
+/// Nominal em size of shapes in layout units, from which the coverage curve
+/// measures their pixels per em like the default font size of CSS.
+const SHAPE_EM: f32 = 16.0;
+
+/// Maximum deviation of curves in SVG documents, in layout units.
+const SHAPE_TOLERANCE: f32 = 0.05;
+
+/// Maximum deviation of quadratic approximations to cubic outlines, in ems.
+const CUBIC_TOLERANCE: f32 = 0.001;
+
+/// Number of instances an animated variation axis is sampled at.
+const ANIMATION_STEPS: usize = 8;
+
+/// Number of frames each step of an animated variation axis is shown for.
+const FRAMES_PER_STEP: usize = 6;
+
+/// Vertex shader sources, each followed by the `dilate()` helper they share.
+const VERT_SRC: &str = concat!(include_str!("vert.glsl"), include_str!("dilate.glsl"));
+const VERT_INSTANCED_SRC: &str = concat!(
+    include_str!("vert_instanced.glsl"),
+    include_str!("dilate.glsl")
+);
+
+/// One mesh per frame of the animation, or a single mesh for still text.
+enum GlyphMesh {
+    Vertices(Program<GlyphUniforms, GlyphVertex>, Vec<Mesh<GlyphVertex>>),
+    Instances(
+        Program<GlyphUniforms, GlyphInstance>,
+        Vec<Mesh<GlyphInstance>>,
+    ),
+}
+
+impl GlyphMesh {
+    fn len(&self) -> usize {
+        match self {
+            GlyphMesh::Vertices(_, meshes) => meshes.len(),
+            GlyphMesh::Instances(_, meshes) => meshes.len(),
+        }
+    }
+
+    fn draw(&self, uniforms: &GlyphUniforms, index: usize) {
+        match self {
+            GlyphMesh::Vertices(prog, meshes) => prog.draw(uniforms, &meshes[index]),
+            GlyphMesh::Instances(prog, meshes) => prog.draw(uniforms, &meshes[index]),
+        }
+    }
+}
+
 struct GouacheHandler {
     timers: VecDeque<TimerQuery>,
-    prog: Program<GlyphUniforms, GlyphVertex>,
-    mesh: Mesh<GlyphVertex>,
+    glyphs: GlyphMesh,
+    coverage_curve: CoverageCurve,
+    subpixel_mode: SubpixelMode,
     components: Texture,
     points: Texture,
+    /// Shapes from an SVG document, drawn with the glyph shaders.
+    shapes: Option<(Program<GlyphUniforms, GlyphVertex>, Mesh<GlyphVertex>)>,
 
     layout: TextLayout,
+    /// Fixed screen-space transform for flat 2D text, replacing the camera.
+    flat: Option<Mat4x4>,
 
     dragging: bool,
     cursor: Vec2,
     z: f32,
     rotate: Mat4x4,
+    frame: usize,
 }
 
 impl Handler for GouacheHandler {
@@ -415,29 +1875,53 @@ impl Handler for GouacheHandler {
             gl::Clear(gl::COLOR_BUFFER_BIT);
         }
 
-        let model = Mat4x4::scale(0.1)
-            * Mat4x4::translate(-0.5 * self.layout.width(), 0.25 * self.layout.height(), 0.0);
-        let view = Mat4x4::translate(0.0, 0.0, -1.0 - self.z) * self.rotate;
-        let proj = Mat4x4::perspective(
-            std::f32::consts::PI / 4.0,
-            SCREEN_WIDTH / SCREEN_HEIGHT,
-            0.1,
-            10000.0,
-        );
-        let transform = proj * view * model;
+        let transform = if let Some(flat) = self.flat {
+            flat
+        } else {
+            let model = Mat4x4::scale(0.1)
+                * Mat4x4::translate(-0.5 * self.layout.width(), 0.25 * self.layout.height(), 0.0);
+            let view = Mat4x4::translate(0.0, 0.0, -1.0 - self.z) * self.rotate;
+            let proj = Mat4x4::perspective(
+                std::f32::consts::PI / 4.0,
+                SCREEN_WIDTH / SCREEN_HEIGHT,
+                0.1,
+                10000.0,
+            );
+            proj * view * model
+        };
 
         let timer = TimerQuery::new();
         timer.begin();
 
-        self.prog.draw(
-            &GlyphUniforms {
-                screen_size: [SCREEN_WIDTH, SCREEN_HEIGHT],
-                transform: transform.0,
-                components: self.components.id(),
-                points: self.points.id(),
-            },
-            &self.mesh,
-        );
+        // Sweep back and forth through the animation steps.
+        let steps = self.glyphs.len();
+        let index = if steps <= 1 {
+            0
+        } else {
+            let period = 2 * (steps - 1);
+            let step = (self.frame / FRAMES_PER_STEP) % period;
+            if step < steps {
+                step
+            } else {
+                period - step
+            }
+        };
+        self.frame += 1;
+
+        let (coverage_mode, coverage_params) = self.coverage_curve.uniforms();
+        let uniforms = GlyphUniforms {
+            screen_size: [SCREEN_WIDTH, SCREEN_HEIGHT],
+            transform: transform.0,
+            coverage_mode,
+            coverage_params,
+            subpixel_mode: self.subpixel_mode as u32,
+            components: self.components.id(),
+            points: self.points.id(),
+        };
+        self.glyphs.draw(&uniforms, index);
+        if let Some((prog, mesh)) = &self.shapes {
+            prog.draw(&uniforms, mesh);
+        }
 
         timer.end();
         self.timers.push_back(timer);
@@ -451,55 +1935,406 @@ fn main() {
 
     let timers: VecDeque<TimerQuery> = VecDeque::with_capacity(64);
 
-    let prog = Program::new(
-        &CString::new(include_bytes!("vert.glsl") as &[u8]).unwrap(),
-        &CString::new(include_bytes!("frag.glsl") as &[u8]).unwrap(),
-    )
-    .unwrap();
+    // Pass `--coverage=linear|quadratic|gamma=<exponent>|contrast` to pick the
+    // coverage curve and `--srgb-blending` to blend sRGB-encoded values
+    // directly instead of in linear space.
+    let coverage_curve = std::env::args()
+        .find_map(|arg| {
+            arg.strip_prefix("--coverage=")
+                .and_then(CoverageCurve::parse)
+        })
+        .unwrap_or(CoverageCurve::Quadratic);
+    let srgb = !std::env::args().any(|arg| arg == "--srgb-blending");
+
+    // Pass `--subpixel=rgb|bgr` to enable LCD subpixel antialiasing.
+    let subpixel_mode = std::env::args()
+        .find_map(|arg| match arg.strip_prefix("--subpixel=")? {
+            "rgb" => Some(SubpixelMode::Rgb),
+            "bgr" => Some(SubpixelMode::Bgr),
+            _ => None,
+        })
+        .unwrap_or(SubpixelMode::Off);
+
+    // Pass `--stem-darkening` to offset outlines outward at small sizes.
+    let stem_darkening = if std::env::args().any(|arg| arg == "--stem-darkening") {
+        StemDarkening {
+            amount: 0.04,
+            max_ppem: 36.0,
+        }
+    } else {
+        StemDarkening::none()
+    };
 
     unsafe {
-        gl::BlendFunc(gl::ONE, gl::ONE_MINUS_SRC_ALPHA);
+        // The fragment shader writes per-channel coverage to the second
+        // output, which covers the grayscale case as well.
+        gl::BlendFunc(gl::ONE, gl::ONE_MINUS_SRC1_COLOR);
         gl::Enable(gl::BLEND);
-        gl::Enable(gl::FRAMEBUFFER_SRGB);
+        if srgb {
+            gl::Enable(gl::FRAMEBUFFER_SRGB);
+        }
+    }
+
+    // Pass `--font=<path>` to load another font, such as a variable one.
+    let mut text =
+        match std::env::args().find_map(|arg| Some(arg.strip_prefix("--font=")?.to_owned())) {
+            Some(path) => {
+                let data = std::fs::read(&path).expect("failed to read font");
+                Text::with_font(Box::leak(data.into_boxed_slice()), TEXTURE_WIDTH)
+                    .expect("failed to parse font")
+            }
+            None => Text::with_texture_width(TEXTURE_WIDTH),
+        };
+    text.set_linear_colors(srgb);
+
+    // Pass `--bold` and/or `--oblique` to synthesize those styles.
+    let mut synthesis = Synthesis::none();
+    if std::env::args().any(|arg| arg == "--bold") {
+        synthesis.embolden = 0.02;
+    }
+    if std::env::args().any(|arg| arg == "--oblique") {
+        synthesis.slant = 0.2;
+    }
+
+    // Pass `--underline`, `--strikethrough` and/or `--overline` to decorate
+    // the text, and `--skip-ink` to interrupt underlines at descenders.
+    let decorations = Decorations {
+        underline: std::env::args().any(|arg| arg == "--underline"),
+        strikethrough: std::env::args().any(|arg| arg == "--strikethrough"),
+        overline: std::env::args().any(|arg| arg == "--overline"),
+        skip_ink: std::env::args().any(|arg| arg == "--skip-ink"),
+    };
+
+    // Pass `--2d` to draw flat text with one layout unit per pixel, and
+    // `--snap[=<steps>]` to also snap baselines to pixels and glyph origins
+    // to `steps` positions per pixel (4 by default). `--hinting` rounds
+    // vertical metrics to whole pixels.
+    let snap_steps = std::env::args().find_map(|arg| match arg.as_str() {
+        "--snap" => Some(4),
+        _ => arg.strip_prefix("--snap=")?.parse().ok(),
+    });
+    let flat = (snap_steps.is_some() || std::env::args().any(|arg| arg == "--2d")).then(|| {
+        Mat4x4::ortho(0.0, SCREEN_WIDTH, -SCREEN_HEIGHT, 0.0, -1.0, 1.0)
+            * Mat4x4::translate(FLAT_MARGIN, -FLAT_MARGIN, 0.0)
+    });
+    let snapping = match snap_steps {
+        Some(steps) => PixelSnapping {
+            origin: Vec2::new(FLAT_MARGIN, -FLAT_MARGIN),
+            pixels_per_unit: 1.0,
+            baselines: true,
+            subpixel_positions: Some(steps),
+        },
+        None => PixelSnapping::none(),
+    };
+
+    // Pass `--variation=<axis>=<value>` (repeatable) to set axis coordinates,
+    // and `--animate=<axis>` to sweep an axis across its range.
+    let variations: Vec<Variation> = std::env::args()
+        .filter_map(|arg| {
+            let (axis, value) = arg.strip_prefix("--variation=")?.split_once('=')?;
+            let axis: [u8; 4] = axis.as_bytes().try_into().ok()?;
+            Some(Variation::new(&axis, value.parse().ok()?))
+        })
+        .collect();
+    let animated_axis = std::env::args().find_map(|arg| {
+        let axis: [u8; 4] = arg.strip_prefix("--animate=")?.as_bytes().try_into().ok()?;
+        let range = text.variation_axis(&axis);
+        if range.is_none() {
+            println!("font has no {} axis", String::from_utf8_lossy(&axis));
+        }
+        Some((axis, range?))
+    });
+
+    // Each animation step is a separate instance, all sharing one atlas.
+    let instances: Vec<Vec<Variation>> = match animated_axis {
+        Some((axis, range)) => (0..ANIMATION_STEPS)
+            .map(|step| {
+                let t = step as f32 / (ANIMATION_STEPS - 1) as f32;
+                let value = range.min_value + t * (range.max_value - range.min_value);
+                let mut instance = variations.clone();
+                instance.retain(|variation| variation.axis != range.tag);
+                instance.push(Variation::new(&axis, value));
+                instance
+            })
+            .collect(),
+        None => vec![variations],
+    };
+
+    // Pass `--palette=<index>` to choose the palette of color glyphs.
+    let palette = std::env::args()
+        .find_map(|arg| arg.strip_prefix("--palette=")?.parse().ok())
+        .unwrap_or(0);
+    let palettes = text.palettes();
+    if palette >= palettes && palettes > 0 {
+        println!("font has only {} palettes", palettes);
     }
 
-    let mut text = Text::with_texture_width(TEXTURE_WIDTH);
+    let mut run = TextRun::new(TEXT);
+    run.palette = palette;
+    run.synthesis = synthesis;
+    run.decorations = decorations;
+    // Pass `--max-lines=<n>` and/or `--max-width=<width>` to clamp the text,
+    // and `--truncate=start|middle|end` to choose where overflow is cut.
+    // `--tab-interval=<ems>` sets the spacing of tab stops.
+    // Pass `--vertical` for vertical text.
+    let options = LayoutOptions {
+        direction: if std::env::args().any(|arg| arg == "--vertical") {
+            Direction::Vertical
+        } else {
+            Direction::Horizontal
+        },
+        max_lines: std::env::args().find_map(|arg| arg.strip_prefix("--max-lines=")?.parse().ok()),
+        max_width: std::env::args().find_map(|arg| arg.strip_prefix("--max-width=")?.parse().ok()),
+        truncation: std::env::args()
+            .find_map(|arg| match arg.strip_prefix("--truncate=")? {
+                "start" => Some(Truncation::Start),
+                "middle" => Some(Truncation::Middle),
+                "end" => Some(Truncation::End),
+                _ => None,
+            })
+            .unwrap_or_default(),
+        tab_stops: TabStops {
+            interval: std::env::args()
+                .find_map(|arg| arg.strip_prefix("--tab-interval=")?.parse().ok())
+                .unwrap_or(TabStops::default().interval),
+            ..TabStops::default()
+        },
+        snapping,
+        hinting: std::env::args().any(|arg| arg == "--hinting"),
+        stem_darkening,
+    };
+
+    let layouts: Vec<TextLayout> = instances
+        .iter()
+        .map(|instance| {
+            let run = TextRun {
+                variations: instance,
+                ..run
+            };
+            text.layout(SIZE, &[run], &options)
+        })
+        .collect();
+    if layouts[0].truncated() {
+        println!("text truncated");
+    }
+    let layout = &layouts[0];
 
-    let layout = text.layout(SIZE, TEXT);
-    let mesh = text.mesh(&layout);
+    // Pass `--vertices` to compare against four vertices per glyph.
+    let glyphs = if std::env::args().any(|arg| arg == "--vertices") {
+        let prog = Program::new(
+            &CString::new(VERT_SRC).unwrap(),
+            &CString::new(include_bytes!("frag.glsl") as &[u8]).unwrap(),
+        )
+        .unwrap();
+        let meshes: Vec<_> = layouts.iter().map(|layout| text.mesh(layout)).collect();
+        println!("vertex mesh: {} bytes", meshes[0].size());
+        GlyphMesh::Vertices(prog, meshes)
+    } else {
+        let prog = Program::new(
+            &CString::new(VERT_INSTANCED_SRC).unwrap(),
+            &CString::new(include_bytes!("frag.glsl") as &[u8]).unwrap(),
+        )
+        .unwrap();
+        let meshes: Vec<_> = layouts
+            .iter()
+            .map(|layout| text.instances(layout))
+            .collect();
+        println!("instance mesh: {} bytes", meshes[0].size());
+        GlyphMesh::Instances(prog, meshes)
+    };
 
-    let (components_width, components_height) = text.components_dimensions();
+    // Pass `--svg=<path>` to draw an SVG document below the text.
+    let shapes = std::env::args()
+        .find_map(|arg| Some(arg.strip_prefix("--svg=")?.to_owned()))
+        .and_then(|path| {
+            let source = std::fs::read_to_string(&path).expect("failed to read SVG document");
+            match parse_document(&source, SHAPE_TOLERANCE) {
+                Ok(document) => Some(document),
+                Err(error) => {
+                    println!("{}: {}", path, error);
+                    None
+                }
+            }
+        })
+        .map(|document| {
+            let prog = Program::new(
+                &CString::new(VERT_SRC).unwrap(),
+                &CString::new(include_bytes!("frag.glsl") as &[u8]).unwrap(),
+            )
+            .unwrap();
+            // Center the document horizontally below the text.
+            let origin = Vec2::new(
+                0.5 * (layout.width() - document.size.x),
+                -layout.height() - SIZE,
+            );
+            let mesh = text.shape_mesh(document.shapes, origin);
+            (prog, mesh)
+        });
+
+    let (components_width, components_height) = text.atlas().components_dimensions();
     let components = unsafe {
         Texture::new(
             TextureFormat::Rg16Ui,
             components_width,
             components_height,
-            text.components().as_ptr() as *const c_void,
+            text.atlas().components().as_ptr() as *const c_void,
         )
     };
 
-    let (points_width, points_height) = text.points_dimensions();
+    let (points_width, points_height) = text.atlas().points_dimensions();
     let points = unsafe {
         Texture::new(
             TextureFormat::Rg16Unorm,
             points_width,
             points_height,
-            text.points().as_ptr() as *const c_void,
+            text.atlas().points().as_ptr() as *const c_void,
         )
     };
 
+    let layout = layouts.into_iter().next().unwrap();
+
     window.run(GouacheHandler {
         timers,
-        prog,
-        mesh,
+        glyphs,
+        coverage_curve,
+        subpixel_mode,
         components,
         points,
 
+        shapes,
         layout,
+        flat,
 
         dragging: false,
         cursor: Vec2::new(-1.0, -1.0),
         z: 70.0,
         rotate: Mat4x4::id(),
+        frame: 0,
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coverage_gamma_must_be_positive() {
+        assert!(matches!(
+            CoverageCurve::parse("gamma=2.2"),
+            Some(CoverageCurve::Gamma(gamma)) if gamma == 2.2
+        ));
+        for value in ["gamma=0", "gamma=-1", "gamma=NaN", "gamma=inf", "gamma="] {
+            assert!(CoverageCurve::parse(value).is_none(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn emboldened_glyphs_keep_their_side_bearing() {
+        let mut text = Text::with_texture_width(TEXTURE_WIDTH);
+        let mut left_edges = Vec::new();
+        for embolden in [0.0, 0.05] {
+            let run = TextRun {
+                synthesis: Synthesis {
+                    embolden,
+                    ..Synthesis::none()
+                },
+                ..TextRun::new("Hl")
+            };
+            let layout = text.layout(SIZE, &[run], &LayoutOptions::default());
+            let glyph = &layout.glyphs[1];
+            let min = text.glyph_entry(glyph.key).path.min;
+            left_edges.push(glyph.pos.x + layout.scale * min.x);
+        }
+
+        // The grown outline starts where the regular one did, after the
+        // wider advance of the emboldened 'H'.
+        let expected = left_edges[0] + 2.0 * 0.05 * SIZE;
+        assert!((left_edges[1] - expected).abs() < 1e-3);
+    }
+
+    #[test]
+    fn ellipsis_fits_across_mixed_runs() {
+        let mut text = Text::with_texture_width(TEXTURE_WIDTH);
+        let runs = [
+            TextRun {
+                synthesis: Synthesis {
+                    embolden: 0.25,
+                    ..Synthesis::none()
+                },
+                ..TextRun::new("bbbbbbbb")
+            },
+            TextRun::new("aaaa"),
+        ];
+        let full = text.layout(SIZE, &runs, &LayoutOptions::default()).width();
+
+        // Start where even the emboldened ellipsis fits on its own.
+        for truncation in [Truncation::Start, Truncation::Middle, Truncation::End] {
+            for i in 2..10 {
+                let max_width = i as f32 * full / 10.0;
+                let options = LayoutOptions {
+                    max_width: Some(max_width),
+                    truncation,
+                    ..LayoutOptions::default()
+                };
+                let layout = text.layout(SIZE, &runs, &options);
+                assert!(layout.truncated());
+                assert!(
+                    layout.width() <= max_width + 1e-3,
+                    "{:?}: {} > {}",
+                    truncation,
+                    layout.width(),
+                    max_width
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn instances_ignore_coordinate_order() {
+        let mut text = Text::with_texture_width(TEXTURE_WIDTH);
+        let bold_narrow = text.instance(&[
+            Variation::new(b"wght", 700.0),
+            Variation::new(b"wdth", 80.0),
+        ]);
+        let narrow_bold = text.instance(&[
+            Variation::new(b"wdth", 80.0),
+            Variation::new(b"wght", 700.0),
+        ]);
+        assert_eq!(bold_narrow, narrow_bold);
+
+        // The last coordinate on an axis wins.
+        let overridden = text.instance(&[
+            Variation::new(b"wght", 400.0),
+            Variation::new(b"wdth", 80.0),
+            Variation::new(b"wght", 700.0),
+        ]);
+        assert_eq!(overridden, bold_narrow);
+        assert_eq!(text.instance(&[]), 0);
+        assert_eq!(text.instances.len(), 2);
+    }
+
+    #[test]
+    fn srgb_colors_decode_to_linear() {
+        let color = srgb_to_linear([0.0, 0.5, 1.0, 0.5]);
+        assert_eq!(color[0], 0.0);
+        assert!((color[1] - 0.214).abs() < 1e-3);
+        assert!((color[2] - 1.0).abs() < 1e-6);
+        assert_eq!(color[3], 0.5);
+        // The linear segment near black.
+        assert!((srgb_to_linear([0.04, 0.0, 0.0, 1.0])[0] - 0.04 / 12.92).abs() < 1e-7);
+    }
+
+    #[test]
+    fn stem_darkening_fades_out_by_whole_ppem() {
+        let darkening = StemDarkening {
+            amount: 0.04,
+            max_ppem: 36.0,
+        };
+        assert_eq!(darkening.offset(0.0), 0.04);
+        assert_eq!(darkening.offset(18.0), 0.02);
+        assert_eq!(darkening.offset(17.8), darkening.offset(18.2));
+        assert_eq!(darkening.offset(36.0), 0.0);
+        assert_eq!(darkening.offset(72.0), 0.0);
+        assert_eq!(StemDarkening::none().offset(8.0), 0.0);
+    }
+}
@@ -1,13 +1,140 @@
+use std::collections::HashMap;
+
 use crate::geom::*;
 
+/// The rule used to decide which regions enclosed by a path are filled.
+///
+/// The discriminants match the `FILL_*` constants in `frag.glsl`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum FillRule {
+    /// Fill regions with a nonzero winding number.
+    NonZero = 0,
+    /// Fill regions with an odd winding number.
+    EvenOdd = 1,
+}
+
+/// Maximum distance allowed between a curve and its quadratic
+/// approximation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Tolerance {
+    /// A distance in path units.
+    Absolute(f32),
+    /// A fraction of the em size, given in path units.
+    Em { fraction: f32, units_per_em: f32 },
+}
+
+impl Tolerance {
+    /// Returns the tolerance in path units.
+    pub fn distance(self) -> f32 {
+        match self {
+            Tolerance::Absolute(distance) => distance,
+            Tolerance::Em {
+                fraction,
+                units_per_em,
+            } => fraction * units_per_em,
+        }
+    }
+}
+
+/// How the outer side of a corner between stroked segments is drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineJoin {
+    Miter,
+    Round,
+    Bevel,
+}
+
+/// How the ends of open stroked contours are drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub join: LineJoin,
+    /// Maximum ratio of a miter's length to the stroke width, beyond which
+    /// miter joins are beveled.
+    pub miter_limit: f32,
+    pub cap: LineCap,
+}
+
+impl StrokeStyle {
+    /// Returns a style with the given width and the defaults of SVG: miter
+    /// joins with a limit of 4 and butt caps.
+    pub fn new(width: f32) -> StrokeStyle {
+        StrokeStyle {
+            width,
+            join: LineJoin::Miter,
+            miter_limit: 4.0,
+            cap: LineCap::Butt,
+        }
+    }
+}
+
+/// A set operation combining the regions filled by two paths.
+#[allow(unused)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    /// The first path with the second cut out of it.
+    Difference,
+    Xor,
+}
+
+impl BooleanOp {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            BooleanOp::Union => a || b,
+            BooleanOp::Intersection => a && b,
+            BooleanOp::Difference => a && !b,
+            BooleanOp::Xor => a != b,
+        }
+    }
+}
+
+/// A command of a path, with the points in path units.
+#[allow(unused)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PathSegment {
+    /// Starts a new contour at the point.
+    MoveTo(Vec2),
+    /// A line from the current point to the point.
+    Line(Vec2),
+    /// A quadratic from the current point with the given control and end
+    /// points.
+    Quad(Vec2, Vec2),
+    /// Marks that the contour was closed, after the segment that returns
+    /// to its start.
+    Close,
+}
+
+/// The direction in which a contour winds, with y pointing up.
+#[allow(unused)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
 pub struct Component {
     start: usize,
     end: usize,
+    /// Whether the contour was ended with `close`, rather than merely
+    /// ending where it started.
+    closed: bool,
 }
 
 pub struct PathBuilder {
     components: Vec<Component>,
     points: Vec<Vec2>,
+    fill_rule: FillRule,
+    tolerance: Tolerance,
 }
 
 impl PathBuilder {
@@ -15,9 +142,29 @@ impl PathBuilder {
         PathBuilder {
             components: Vec::new(),
             points: Vec::new(),
+            fill_rule: FillRule::NonZero,
+            tolerance: Tolerance::Absolute(0.1),
         }
     }
 
+    pub fn fill_rule(&mut self, fill_rule: FillRule) -> &mut Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// Sets the tolerance used to approximate curves that are added
+    /// afterwards.
+    ///
+    /// The default of a tenth of a unit is meant for paths in pixels, where
+    /// it is well below what antialiasing can show. Outlines in font units
+    /// should use [`Tolerance::Em`] instead, as glyphs do with a thousandth
+    /// of an em, so that the bound follows the em size rather than the units
+    /// per em of the font.
+    pub fn tolerance(&mut self, tolerance: Tolerance) -> &mut Self {
+        self.tolerance = tolerance;
+        self
+    }
+
     pub fn add_point(&mut self, point: Vec2) {
         if let Some(component) = self.components.last_mut() {
             component.end += 1;
@@ -29,6 +176,7 @@ impl PathBuilder {
         self.components.push(Component {
             start: self.points.len(),
             end: self.points.len(),
+            closed: false,
         });
         self.add_point(point);
         self
@@ -47,13 +195,9 @@ impl PathBuilder {
     }
 
     pub fn cubic_to(&mut self, control1: Vec2, control2: Vec2, point: Vec2) -> &mut Self {
-        let last = self.points.last().cloned().unwrap_or(Vec2::new(0.0, 0.0));
-
-        let width = last.x.max(control1.x).max(control2.x).max(point.x)
-            - last.x.min(control1.x).min(control2.x).min(point.x);
-        let height = last.y.max(control1.y).max(control2.y).max(point.y)
-            - last.y.min(control1.y).min(control2.y).min(point.y);
-        let factor = 0.001 * width.max(height) * 18.0 / 3.0f32.sqrt();
+        // Approximating a cubic over a parameter interval of length `s` with
+        // one quadratic deviates by at most `sqrt(3) / 18 * s^3 * error`.
+        let factor = self.tolerance.distance() * 18.0 / 3.0f32.sqrt();
 
         let mut p1 = self.points.last().cloned().unwrap_or(Vec2::new(0.0, 0.0));
         let mut p2 = control1;
@@ -86,8 +230,219 @@ impl PathBuilder {
         self
     }
 
+    /// Adds an elliptical arc to `point` in the endpoint form used by SVG,
+    /// approximated by quadratics within the builder's tolerance.
+    ///
+    /// `rotation` is the angle of the ellipse's x axis in radians. Of the four
+    /// candidate arcs, `large_arc` picks one spanning more than 180 degrees
+    /// and `sweep` one drawn in the direction of increasing angle.
+    pub fn arc_to(
+        &mut self,
+        radii: Vec2,
+        rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        point: Vec2,
+    ) -> &mut Self {
+        let last = self.points.last().cloned().unwrap_or(Vec2::new(0.0, 0.0));
+        let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+        if rx == 0.0 || ry == 0.0 || last == point {
+            return self.line_to(point);
+        }
+
+        // Convert to center parameterization, following the SVG
+        // implementation notes.
+        let (sin, cos) = rotation.sin_cos();
+        let half = 0.5 * (last - point);
+        let p = Vec2::new(cos * half.x + sin * half.y, -sin * half.x + cos * half.y);
+
+        // Scale up radii that are too small to reach the endpoint.
+        let lambda = (p.x * p.x) / (rx * rx) + (p.y * p.y) / (ry * ry);
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * p.y * p.y - ry * ry * p.x * p.x;
+        let denominator = rx * rx * p.y * p.y + ry * ry * p.x * p.x;
+        let mut factor = (numerator / denominator).max(0.0).sqrt();
+        if large_arc == sweep {
+            factor = -factor;
+        }
+        let center_prime = Vec2::new(factor * rx * p.y / ry, -factor * ry * p.x / rx);
+        let mid = 0.5 * (last + point);
+        let center = Vec2::new(
+            cos * center_prime.x - sin * center_prime.y + mid.x,
+            sin * center_prime.x + cos * center_prime.y + mid.y,
+        );
+
+        let angle = |v: Vec2| v.y.atan2(v.x);
+        let start = angle(Vec2::new(
+            (p.x - center_prime.x) / rx,
+            (p.y - center_prime.y) / ry,
+        ));
+        let end = angle(Vec2::new(
+            (-p.x - center_prime.x) / rx,
+            (-p.y - center_prime.y) / ry,
+        ));
+        let mut delta = end - start;
+        if sweep && delta < 0.0 {
+            delta += 2.0 * std::f32::consts::PI;
+        } else if !sweep && delta > 0.0 {
+            delta -= 2.0 * std::f32::consts::PI;
+        }
+
+        self.elliptical_arc(center, Vec2::new(rx, ry), rotation, start, delta, point)
+    }
+
+    /// Adds quadratics along the ellipse with the given center, radii and
+    /// rotation from angle `start` through `delta` radians, ending exactly
+    /// at `end`.
+    fn elliptical_arc(
+        &mut self,
+        center: Vec2,
+        radii: Vec2,
+        rotation: f32,
+        start: f32,
+        delta: f32,
+        end: Vec2,
+    ) -> &mut Self {
+        // A quadratic with its control point where the tangents at the ends
+        // of a unit circle arc meet strays furthest at its midpoint, by
+        // `(cos(h) + sec(h)) / 2 - 1` for half-angle `h`. Stretching by the
+        // radii scales this by at most the larger one.
+        let radius = radii.x.max(radii.y);
+        let tolerance = self.tolerance.distance();
+        let error = |half: f32| radius * (0.5 * (half.cos() + 1.0 / half.cos()) - 1.0);
+
+        let mut segments = (delta.abs() / std::f32::consts::FRAC_PI_2).ceil().max(1.0) as usize;
+        while segments < 1024 && error(0.5 * delta.abs() / segments as f32) > tolerance {
+            segments += 1;
+        }
+
+        let (sin, cos) = rotation.sin_cos();
+        let map = |v: Vec2| {
+            let v = Vec2::new(radii.x * v.x, radii.y * v.y);
+            center + Vec2::new(cos * v.x - sin * v.y, sin * v.x + cos * v.y)
+        };
+
+        let step = delta / segments as f32;
+        for i in 0..segments {
+            let middle = start + (i as f32 + 0.5) * step;
+            let control = (1.0 / (0.5 * step).cos()) * Vec2::new(middle.cos(), middle.sin());
+            let angle = start + (i + 1) as f32 * step;
+            let point = if i + 1 == segments {
+                end
+            } else {
+                map(Vec2::new(angle.cos(), angle.sin()))
+            };
+            self.quadratic_to(map(control), point);
+        }
+
+        self
+    }
+
+    /// Adds a line toward `corner` and a circular arc of `radius` tangent to
+    /// the lines from the current point to `corner` and from `corner` to
+    /// `point`, like `arcTo` in the HTML canvas API. The path ends where the
+    /// arc meets the second line, not at `point`.
+    #[allow(unused)]
+    pub fn tangent_arc_to(&mut self, corner: Vec2, point: Vec2, radius: f32) -> &mut Self {
+        let last = self.points.last().cloned().unwrap_or(Vec2::new(0.0, 0.0));
+        let incoming = last - corner;
+        let outgoing = point - corner;
+        let turn = (corner - last).cross(outgoing);
+        if radius <= 0.0 || incoming.length() == 0.0 || outgoing.length() == 0.0 || turn == 0.0 {
+            return self.line_to(corner);
+        }
+
+        let (incoming, outgoing) = (incoming.normalized(), outgoing.normalized());
+        let half = 0.5 * incoming.dot(outgoing).clamp(-1.0, 1.0).acos();
+        let distance = radius / half.tan();
+        let start = corner + distance * incoming;
+        let end = corner + distance * outgoing;
+
+        self.line_to(start);
+        // Turning left runs counterclockwise, in the direction of increasing
+        // angle.
+        self.arc_to(Vec2::new(radius, radius), 0.0, false, turn > 0.0, end)
+    }
+
+    /// Adds a closed circle as a new contour, running counterclockwise.
+    pub fn circle(&mut self, center: Vec2, radius: f32) -> &mut Self {
+        self.ellipse(center, Vec2::new(radius, radius))
+    }
+
+    /// Adds a closed axis-aligned ellipse as a new contour, running
+    /// counterclockwise.
+    pub fn ellipse(&mut self, center: Vec2, radii: Vec2) -> &mut Self {
+        let start = center + Vec2::new(radii.x, 0.0);
+        self.move_to(start);
+        self.elliptical_arc(center, radii, 0.0, 0.0, 2.0 * std::f32::consts::PI, start);
+        self.close();
+        self
+    }
+
+    /// Adds a closed rectangle with corners `min` and `max` as a new contour,
+    /// running counterclockwise.
+    pub fn rect(&mut self, min: Vec2, max: Vec2) -> &mut Self {
+        self.move_to(min)
+            .line_to(Vec2::new(max.x, min.y))
+            .line_to(max)
+            .line_to(Vec2::new(min.x, max.y));
+        self.close();
+        self
+    }
+
+    /// Adds a closed rectangle with elliptical corners as a new contour,
+    /// running counterclockwise. `radii` start at the corner at `min` and
+    /// continue counterclockwise. Radii are scaled down uniformly where
+    /// adjacent corners would overlap, as in CSS.
+    pub fn rounded_rect(&mut self, min: Vec2, max: Vec2, radii: [Vec2; 4]) -> &mut Self {
+        let size = max - min;
+        let radii = radii.map(|radius| Vec2::new(radius.x.max(0.0), radius.y.max(0.0)));
+        let fit = |side: f32, a: f32, b: f32| if a + b > side { side / (a + b) } else { 1.0 };
+        let factor = fit(size.x, radii[0].x, radii[1].x)
+            .min(fit(size.x, radii[3].x, radii[2].x))
+            .min(fit(size.y, radii[0].y, radii[3].y))
+            .min(fit(size.y, radii[1].y, radii[2].y));
+        let radii = radii.map(|radius| factor * radius);
+
+        let corners = [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)];
+        // Direction from each corner toward the inside of the rectangle.
+        let inward = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(1.0, -1.0),
+        ];
+
+        let quarter = std::f32::consts::FRAC_PI_2;
+        self.move_to(corners[0] + Vec2::new(radii[0].x, 0.0));
+        for i in 0..4 {
+            let corner = (i + 1) % 4;
+            let radius = radii[corner];
+            let center = corners[corner]
+                + Vec2::new(inward[corner].x * radius.x, inward[corner].y * radius.y);
+
+            // The arc at corner `i` turns from `(i - 2) * 90` degrees to the
+            // next quarter turn.
+            let start = (corner as f32 - 2.0) * quarter;
+            let (sin, cos) = start.sin_cos();
+            let (end_sin, end_cos) = (start + quarter).sin_cos();
+            self.line_to(center + Vec2::new(radius.x * cos, radius.y * sin));
+            if radius.x > 0.0 && radius.y > 0.0 {
+                let end = center + Vec2::new(radius.x * end_cos, radius.y * end_sin);
+                self.elliptical_arc(center, radius, 0.0, start, quarter, end);
+            }
+        }
+        self.close();
+        self
+    }
+
     pub fn close(&mut self) {
         if let Some(component) = self.components.last_mut() {
+            component.closed = true;
             let first = self.points[component.start];
             let last = self.points[component.end - 1];
             if first != last {
@@ -97,29 +452,885 @@ impl PathBuilder {
         }
     }
 
+    /// Adds a segment as produced by [`PathBuilder::iter`].
+    #[allow(unused)]
+    pub fn push_segment(&mut self, segment: PathSegment) -> &mut Self {
+        match segment {
+            PathSegment::MoveTo(point) => self.move_to(point),
+            PathSegment::Line(point) => self.line_to(point),
+            PathSegment::Quad(control, point) => self.quadratic_to(control, point),
+            PathSegment::Close => {
+                self.close();
+                self
+            }
+        }
+    }
+
+    /// Returns the segments of every contour in order, such that adding them
+    /// to a new builder with `push_segment` records the same points.
+    /// Quadratics whose control point is their end point are reported as
+    /// lines, as `line_to` records them, and contours ended with `close` are
+    /// followed by [`PathSegment::Close`].
+    #[allow(unused)]
+    pub fn iter(&self) -> impl Iterator<Item = PathSegment> + '_ {
+        self.components.iter().flat_map(move |component| {
+            let points = &self.points[component.start..component.end];
+            std::iter::once(PathSegment::MoveTo(points[0]))
+                .chain(points.windows(3).step_by(2).map(|window| {
+                    let (control, end) = (window[1], window[2]);
+                    if control == end {
+                        PathSegment::Line(end)
+                    } else {
+                        PathSegment::Quad(control, end)
+                    }
+                }))
+                .chain(component.closed.then_some(PathSegment::Close))
+        })
+    }
+
+    /// Returns the number of contours.
+    #[allow(unused)]
+    pub fn contours(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns the area enclosed by contour `index`, positive if it runs
+    /// counterclockwise with y pointing up. Open contours are measured as if
+    /// closed with a line.
+    #[allow(unused)]
+    pub fn contour_area(&self, index: usize) -> f32 {
+        let component = &self.components[index];
+        let points = &self.points[component.start..component.end];
+
+        // Integrating x dy - y dx over a quadratic gives the area between it
+        // and the origin in closed form.
+        let mut area = 0.0;
+        for window in points.windows(3).step_by(2) {
+            let (a, b, c) = (window[0], window[1], window[2]);
+            area += (2.0 * a.cross(b) + 2.0 * b.cross(c) + a.cross(c)) / 6.0;
+        }
+        area + 0.5 * points[points.len() - 1].cross(points[0])
+    }
+
+    /// Returns the sum of the signed areas of every contour. Like
+    /// `contour_area`, this counts open contours as closed by a line from
+    /// their end back to their start.
+    #[allow(unused)]
+    pub fn signed_area(&self) -> f32 {
+        (0..self.components.len())
+            .map(|index| self.contour_area(index))
+            .sum()
+    }
+
+    /// Returns the direction in which contour `index` winds.
+    #[allow(unused)]
+    pub fn orientation(&self, index: usize) -> Orientation {
+        if self.contour_area(index) < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::CounterClockwise
+        }
+    }
+
+    /// Reverses the direction of contour `index`, keeping its shape.
+    pub fn reverse_contour(&mut self, index: usize) -> &mut Self {
+        let component = &self.components[index];
+        let points = &mut self.points[component.start..component.end];
+        points.reverse();
+
+        // Lines record their control point at the end, as `line_to` does.
+        for i in (1..points.len()).step_by(2) {
+            if points[i] == points[i - 1] {
+                points[i] = points[i + 1];
+            }
+        }
+        self
+    }
+
+    /// Reverses the direction of every contour.
+    #[allow(unused)]
+    pub fn reverse(&mut self) -> &mut Self {
+        for index in 0..self.components.len() {
+            self.reverse_contour(index);
+        }
+        self
+    }
+
+    /// Offsets every contour outward by `amount`, moving each point along the
+    /// miter direction of its neighboring segments. Used for synthetic bold.
+    ///
+    /// Closed contours wrap around, while the ends of open ones move along
+    /// the normal of their only segment. The outline grows by `amount` on
+    /// every side, so callers widen the advance and shift the origin to
+    /// match.
+    pub fn embolden(&mut self, amount: f32) -> &mut Self {
+        let mut area = 0.0;
+        for component in self.components.iter() {
+            let points = &self.points[component.start..component.end];
+            for (i, &point) in points.iter().enumerate() {
+                area += point.cross(points[(i + 1) % points.len()]);
+            }
+        }
+        // Outward normals are to the right of counterclockwise contours.
+        let sign = if area >= 0.0 { 1.0 } else { -1.0 };
+
+        for component in self.components.iter() {
+            // The closing point of a closed contour repeats its start, so it
+            // is left out and moved along with it.
+            let mut end = component.end;
+            let closed = component.closed && end - component.start > 1;
+            let repeated = closed && self.points[component.start] == self.points[end - 1];
+            if repeated {
+                end -= 1;
+            }
+            let points = &self.points[component.start..end];
+            let len = points.len();
+
+            let mut offset = Vec::with_capacity(len);
+            for (i, &point) in points.iter().enumerate() {
+                let prev = (1..len)
+                    .map_while(|j| {
+                        if closed {
+                            Some((i + len - j) % len)
+                        } else {
+                            i.checked_sub(j)
+                        }
+                    })
+                    .map(|j| points[j])
+                    .find(|&p| p != point);
+                let next = (1..len)
+                    .map_while(|j| {
+                        if closed {
+                            Some((i + j) % len)
+                        } else {
+                            (i + j < len).then_some(i + j)
+                        }
+                    })
+                    .map(|j| points[j])
+                    .find(|&p| p != point);
+
+                let (d_in, d_out) = match (prev, next) {
+                    (Some(prev), Some(next)) => {
+                        ((point - prev).normalized(), (next - point).normalized())
+                    }
+                    (Some(prev), None) => {
+                        let d = (point - prev).normalized();
+                        (d, d)
+                    }
+                    (None, Some(next)) => {
+                        let d = (next - point).normalized();
+                        (d, d)
+                    }
+                    (None, None) => {
+                        offset.push(point);
+                        continue;
+                    }
+                };
+                let n_in = sign * Vec2::new(d_in.y, -d_in.x);
+                let n_out = sign * Vec2::new(d_out.y, -d_out.x);
+
+                // Limit the miter length at sharp corners.
+                let d = (1.0 + n_in.dot(n_out)).max(0.25);
+                offset.push(point + (amount / d) * (n_in + n_out));
+            }
+
+            self.points[component.start..end].copy_from_slice(&offset);
+            if repeated {
+                self.points[end] = self.points[component.start];
+            }
+        }
+
+        self
+    }
+
+    /// Scales every point vertically by `scale` about the baseline. Used to
+    /// land the x-height on a pixel boundary when hinting.
+    pub fn stretch_vertically(&mut self, scale: f32) -> &mut Self {
+        self.transform(&Mat2x3::scale(1.0, scale))
+    }
+
+    /// Shears every point horizontally by `slant` times its y coordinate.
+    /// Used for synthetic oblique.
+    pub fn slant(&mut self, slant: f32) -> &mut Self {
+        self.transform(&Mat2x3::skew(slant, 0.0))
+    }
+
+    /// Applies `transform` to every point recorded so far. Curves keep their
+    /// shape under affine transforms, so this places, rotates or shears the
+    /// whole path exactly.
+    ///
+    /// Only the points are mapped: a transform that mirrors the path also
+    /// flips the direction of its contours. Call `reverse` afterwards where
+    /// the orientation matters.
+    pub fn transform(&mut self, transform: &Mat2x3) -> &mut Self {
+        for point in self.points.iter_mut() {
+            *point = transform.transform_point(*point);
+        }
+        self
+    }
+
+    /// Rotates every point a quarter turn clockwise about the origin. Used
+    /// for setting horizontal scripts sideways in vertical text.
+    pub fn rotate_sideways(&mut self) -> &mut Self {
+        // The exact matrix of `Mat2x3::rotate(-FRAC_PI_2)`, free of the
+        // rounding in its sine and cosine.
+        self.transform(&Mat2x3([0.0, 1.0, 0.0, -1.0, 0.0, 0.0]))
+    }
+
+    /// Returns a fill path covering a stroke along every contour, to be
+    /// filled with the nonzero rule. Contours ended with `close` are joined
+    /// where they start; others get caps, even if they end where they start.
+    ///
+    /// Each side of a contour is offset separately, with the curves
+    /// subdivided until their offsets are within the builder's tolerance.
+    /// The inner sides of corners are connected through the corner itself,
+    /// which leaves overlaps that the nonzero rule fills.
+    pub fn stroke(&self, style: &StrokeStyle) -> PathBuilder {
+        let mut stroke = PathBuilder::new();
+        stroke.tolerance(self.tolerance);
+
+        let offset = 0.5 * style.width;
+        if offset <= 0.0 {
+            return stroke;
+        }
+
+        for component in self.components.iter() {
+            let points = &self.points[component.start..component.end];
+            let segments = segments(points);
+
+            // A lone point only shows with round or square caps.
+            if segments.is_empty() {
+                let point = points[0];
+                match style.cap {
+                    LineCap::Butt => {}
+                    LineCap::Round => {
+                        stroke.circle(point, offset);
+                    }
+                    LineCap::Square => {
+                        let corner = Vec2::new(offset, offset);
+                        stroke.rect(point - corner, point + corner);
+                    }
+                }
+                continue;
+            }
+
+            // Offsetting the reversed contour to its left gives the right
+            // side of the original.
+            let reversed: Vec<Segment> =
+                segments.iter().rev().map(|&(a, b, c)| (c, b, a)).collect();
+
+            if component.closed {
+                for side in [&segments, &reversed] {
+                    let (a, b, c) = side[0];
+                    stroke.move_to(a + offset * normal(start_tangent(a, b, c)));
+                    stroke.offset_side(side, offset, style, true);
+                    stroke.close();
+                }
+            } else {
+                let (a, b, c) = segments[0];
+                stroke.move_to(a + offset * normal(start_tangent(a, b, c)));
+                stroke.offset_side(&segments, offset, style, false);
+                let (a, b, c) = segments[segments.len() - 1];
+                stroke.cap(c, end_tangent(a, b, c), offset, style.cap);
+                stroke.offset_side(&reversed, offset, style, false);
+                let (a, b, c) = reversed[reversed.len() - 1];
+                stroke.cap(c, end_tangent(a, b, c), offset, style.cap);
+                stroke.close();
+            }
+        }
+
+        stroke
+    }
+
+    /// Adds the left offsets of `segments` with joins between them, starting
+    /// from the current point at the offset start of the first segment.
+    fn offset_side(
+        &mut self,
+        segments: &[Segment],
+        offset: f32,
+        style: &StrokeStyle,
+        closed: bool,
+    ) {
+        for (i, &(a, b, c)) in segments.iter().enumerate() {
+            if i > 0 {
+                let (prev_a, prev_b, prev_c) = segments[i - 1];
+                let incoming = end_tangent(prev_a, prev_b, prev_c);
+                self.join(a, incoming, start_tangent(a, b, c), offset, style);
+            }
+            self.offset_quadratic(a, b, c, offset, 0);
+        }
+
+        if closed {
+            let (a, b, c) = segments[segments.len() - 1];
+            let (first_a, first_b, first_c) = segments[0];
+            let outgoing = start_tangent(first_a, first_b, first_c);
+            self.join(c, end_tangent(a, b, c), outgoing, offset, style);
+        }
+    }
+
+    /// Adds the join on the left side of `vertex` between segments with the
+    /// given tangents.
+    fn join(
+        &mut self,
+        vertex: Vec2,
+        incoming: Vec2,
+        outgoing: Vec2,
+        offset: f32,
+        style: &StrokeStyle,
+    ) {
+        let (normal_in, normal_out) = (normal(incoming), normal(outgoing));
+        let end = vertex + offset * normal_out;
+
+        let turn = incoming.cross(outgoing);
+        let outer = turn < 0.0 || (turn == 0.0 && incoming.dot(outgoing) < 0.0);
+        if !outer {
+            if (normal_in - normal_out).length() > 1e-6 {
+                self.line_to(vertex);
+            }
+            self.line_to(end);
+            return;
+        }
+
+        match style.join {
+            LineJoin::Bevel => {}
+            LineJoin::Miter => {
+                let direction = normal_in + normal_out;
+                if direction.length() > 1e-6 {
+                    let direction = direction.normalized();
+                    let cos = direction.dot(normal_in);
+                    if cos > 0.0 && 1.0 / cos <= style.miter_limit {
+                        self.line_to(vertex + (offset / cos) * direction);
+                    }
+                }
+            }
+            LineJoin::Round => {
+                // Turning right runs clockwise.
+                self.arc_to(Vec2::new(offset, offset), 0.0, false, false, end);
+                return;
+            }
+        }
+        self.line_to(end);
+    }
+
+    /// Adds a cap at the end of a contour at `point` heading along `tangent`,
+    /// from the left side of the stroke to the right.
+    fn cap(&mut self, point: Vec2, tangent: Vec2, offset: f32, cap: LineCap) {
+        let normal = normal(tangent);
+        let forward = offset * tangent.normalized();
+        let right = point - offset * normal;
+        match cap {
+            LineCap::Butt => {}
+            LineCap::Round => {
+                self.arc_to(Vec2::new(offset, offset), 0.0, false, false, right);
+                return;
+            }
+            LineCap::Square => {
+                self.line_to(point + offset * normal + forward);
+                self.line_to(right + forward);
+            }
+        }
+        self.line_to(right);
+    }
+
+    /// Adds an approximation of the quadratic `a, b, c` offset to its left,
+    /// splitting it until the approximation is within tolerance.
+    fn offset_quadratic(&mut self, a: Vec2, b: Vec2, c: Vec2, offset: f32, depth: usize) {
+        const MAX_DEPTH: usize = 10;
+
+        let (start, end) = (start_tangent(a, b, c), end_tangent(a, b, c));
+        let (normal_start, normal_end) = (normal(start), normal(end));
+        let offset_start = a + offset * normal_start;
+        let offset_end = c + offset * normal_end;
+
+        let split = |builder: &mut PathBuilder| {
+            let ab = Vec2::lerp(0.5, a, b);
+            let bc = Vec2::lerp(0.5, b, c);
+            let middle = Vec2::lerp(0.5, ab, bc);
+            builder.offset_quadratic(a, ab, middle, offset, depth + 1);
+            builder.offset_quadratic(middle, bc, c, offset, depth + 1);
+        };
+
+        // Parallel tangents leave no intersection for the control point.
+        let cross = start.cross(end);
+        if cross.abs() <= 1e-6 * start.length() * end.length() {
+            if start.dot(end) < 0.0 && depth < MAX_DEPTH {
+                split(self);
+            } else {
+                self.line_to(offset_end);
+            }
+            return;
+        }
+
+        // Curves turning sharply are split before fitting.
+        if normal_start.dot(normal_end) < 0.7 && depth < MAX_DEPTH {
+            split(self);
+            return;
+        }
+
+        let s = (offset_end - offset_start).cross(end) / cross;
+        let control = offset_start + s * start;
+
+        // The derivative at the midpoint is parallel to `c - a`.
+        let middle = evaluate(a, b, c, 0.5);
+        let expected = middle + offset * normal(c - a);
+        let error = (evaluate(offset_start, control, offset_end, 0.5) - expected).length();
+        if error > self.tolerance.distance() && depth < MAX_DEPTH {
+            split(self);
+            return;
+        }
+
+        self.quadratic_to(control, offset_end);
+    }
+
+    /// Returns the dashes of every contour as open contours, ready to be
+    /// stroked. `dashes` alternates between the lengths of dashes and gaps,
+    /// and is repeated twice if its length is odd, as in SVG. `offset` is the
+    /// distance into the pattern at which each contour starts.
+    ///
+    /// Dashes of zero length become lone points, which stroke as dots with
+    /// round or square caps. On closed contours a dash running through the
+    /// start is kept in one piece. Contours are returned unchanged if the
+    /// pattern is empty, has no positive lengths, or would split them into
+    /// an unreasonable number of dashes.
+    pub fn dash(&self, dashes: &[f32], offset: f32) -> PathBuilder {
+        const MAX_DASHES: f32 = 100_000.0;
+
+        let mut dashed = PathBuilder::new();
+        dashed.tolerance(self.tolerance);
+        dashed.fill_rule(self.fill_rule);
+
+        let pattern: Vec<f32> = if dashes.len() % 2 == 1 {
+            dashes
+                .iter()
+                .chain(dashes.iter())
+                .map(|&d| d.max(0.0))
+                .collect()
+        } else {
+            dashes.iter().map(|&d| d.max(0.0)).collect()
+        };
+        let period: f32 = pattern.iter().sum();
+
+        for component in self.components.iter() {
+            let points = &self.points[component.start..component.end];
+
+            // Lines keep their control point on an endpoint; moving it to the
+            // midpoint makes their parameterization uniform in length.
+            let segments: Vec<ArcLength> = segments(points)
+                .into_iter()
+                .map(|(a, b, c)| {
+                    if b == a || b == c {
+                        ArcLength::new(a, Vec2::lerp(0.5, a, c), c)
+                    } else {
+                        ArcLength::new(a, b, c)
+                    }
+                })
+                .collect();
+            let length: f32 = segments.iter().map(|segment| segment.length()).sum();
+
+            if !(period > 0.0 && length / period <= MAX_DASHES) {
+                dashed.move_to(points[0]);
+                for pair in points[1..].chunks(2) {
+                    if let [control, point] = *pair {
+                        dashed.quadratic_to(control, point);
+                    }
+                }
+                if component.closed {
+                    dashed.close();
+                }
+                continue;
+            }
+
+            // Find where in the pattern the contour starts.
+            let mut phase = offset.rem_euclid(period);
+            let mut index = 0;
+            while phase > 0.0 && phase >= pattern[index] {
+                phase -= pattern[index];
+                index = (index + 1) % pattern.len();
+            }
+            let mut remaining = pattern[index] - phase;
+
+            let starts_on = index % 2 == 0;
+            let mut pieces: Vec<(Vec2, Vec<Segment>)> = Vec::new();
+            let mut current = starts_on.then(|| (points[0], Vec::new()));
+
+            for segment in segments.iter() {
+                let length = segment.length();
+                let mut distance = 0.0;
+                let mut t = 0.0;
+                loop {
+                    let end = distance + remaining;
+                    if end > length {
+                        if let Some((_, piece)) = current.as_mut() {
+                            if t < 1.0 {
+                                piece.push(segment.subsegment(t, 1.0));
+                            }
+                        }
+                        remaining = end - length;
+                        break;
+                    }
+
+                    let end_t = segment.parameter(end);
+                    if let Some((start, mut piece)) = current.take() {
+                        if end_t > t {
+                            piece.push(segment.subsegment(t, end_t));
+                        }
+                        pieces.push((start, piece));
+                    }
+
+                    distance = end;
+                    t = end_t;
+                    index = (index + 1) % pattern.len();
+                    remaining = pattern[index];
+                    if index % 2 == 0 {
+                        current = Some((segment.point(t), Vec::new()));
+                    }
+                }
+            }
+
+            // A dash that starts exactly at the end of the contour is empty.
+            let current = current.filter(|(_, piece)| !piece.is_empty());
+
+            // A dash running through the whole closed contour stays closed.
+            let mut whole = false;
+            if let Some(last) = current {
+                if component.closed && starts_on && !pieces.is_empty() {
+                    let (_, first) = pieces.remove(0);
+                    let (start, mut joined) = last;
+                    joined.extend(first);
+                    pieces.insert(0, (start, joined));
+                } else {
+                    whole = component.closed && starts_on;
+                    pieces.push(last);
+                }
+            }
+
+            for (start, piece) in pieces {
+                dashed.move_to(start);
+                for (_, control, point) in piece {
+                    dashed.quadratic_to(control, point);
+                }
+            }
+            if whole {
+                dashed.close();
+            }
+        }
+
+        dashed
+    }
+
+    /// Returns the boundary of the region given by combining the regions
+    /// filled by this path and `other`, each under its own fill rule.
+    ///
+    /// The result is made of closed contours that don't cross each other,
+    /// with filled regions on their left, so it fills the same with either
+    /// rule. Edges shared by both paths are merged, and points closer than
+    /// a small fraction of the combined bounds are treated as one.
+    #[allow(unused)]
+    pub fn boolean(&self, other: &PathBuilder, op: BooleanOp) -> PathBuilder {
+        let mut result = PathBuilder::new();
+        result.tolerance(self.tolerance);
+
+        let inputs = [self.monotonic_segments(), other.monotonic_segments()];
+        let fill_rules = [self.fill_rule, other.fill_rule];
+        let all: Vec<Segment> = inputs.iter().flatten().copied().collect();
+        if all.is_empty() {
+            return result;
+        }
+
+        let (mut min, mut max) = (all[0].0, all[0].0);
+        for &(a, _, c) in all.iter() {
+            min = min.min(a).min(c);
+            max = max.max(a).max(c);
+        }
+        let epsilon = 1e-5 * (max - min).length().max(1e-3);
+
+        // Split every segment where it crosses or touches another.
+        let mut splits: Vec<Vec<f32>> = vec![Vec::new(); all.len()];
+        for i in 0..all.len() {
+            for j in i + 1..all.len() {
+                let (s1, s2) = (all[i], all[j]);
+                if !bounds_overlap(s1, s2, epsilon) {
+                    continue;
+                }
+                let mut crossings = Vec::new();
+                intersect(s1, (0.0, 1.0), s2, (0.0, 1.0), epsilon, 0, &mut crossings);
+                for (t1, t2) in crossings {
+                    splits[i].push(t1);
+                    splits[j].push(t2);
+                }
+                // Endpoints lying on the other segment split it, which lines
+                // up overlapping edges.
+                for point in [s2.0, s2.2] {
+                    if let Some(t) = touch(s1, point, epsilon) {
+                        splits[i].push(t);
+                    }
+                }
+                for point in [s1.0, s1.2] {
+                    if let Some(t) = touch(s2, point, epsilon) {
+                        splits[j].push(t);
+                    }
+                }
+            }
+        }
+
+        // Merge nearby endpoints into shared vertices.
+        let mut vertices: Vec<Vec2> = Vec::new();
+        let mut vertex = |point: Vec2| -> usize {
+            match vertices
+                .iter()
+                .position(|&v| (v - point).length() <= 2.0 * epsilon)
+            {
+                Some(index) => index,
+                None => {
+                    vertices.push(point);
+                    vertices.len() - 1
+                }
+            }
+        };
+
+        let mut edges: Vec<(usize, usize, Vec2)> = Vec::new();
+        for (segment, mut ts) in all.iter().zip(splits) {
+            ts.sort_by(f32::total_cmp);
+            let (a, b, c) = *segment;
+            let (mut start, mut start_point) = (0.0, a);
+            for t in ts.into_iter().chain([1.0]) {
+                let end_point = if t < 1.0 { evaluate(a, b, c, t) } else { c };
+                // Splits next to the previous one or the end are dropped.
+                if t < 1.0
+                    && ((end_point - start_point).length() <= 2.0 * epsilon
+                        || (end_point - c).length() <= 2.0 * epsilon)
+                {
+                    continue;
+                }
+                let (_, control, _) = subsegment(*segment, start, t);
+                let (from, to) = (vertex(start_point), vertex(end_point));
+                if from != to {
+                    edges.push((from, to, control));
+                }
+                start = t;
+                start_point = end_point;
+            }
+        }
+
+        // Keep one of each set of coincident edges.
+        let mut seen: HashMap<(usize, usize), Vec<Vec2>> = HashMap::new();
+        edges.retain(|&(from, to, control)| {
+            let middle = evaluate(vertices[from], control, vertices[to], 0.5);
+            let others = seen.entry((from.min(to), from.max(to))).or_default();
+            if others
+                .iter()
+                .any(|&other| (other - middle).length() <= 4.0 * epsilon)
+            {
+                return false;
+            }
+            others.push(middle);
+            true
+        });
+
+        // Keep the edges separating the result from its complement, oriented
+        // with the result on their left.
+        let inside = |point: Vec2| {
+            let mut filled = [false; 2];
+            for k in 0..2 {
+                let winding = winding(&inputs[k], point);
+                filled[k] = match fill_rules[k] {
+                    FillRule::NonZero => winding != 0,
+                    FillRule::EvenOdd => winding % 2 != 0,
+                };
+            }
+            op.apply(filled[0], filled[1])
+        };
+        let mut boundary: Vec<(usize, usize, Vec2)> = Vec::new();
+        for (from, to, control) in edges {
+            let (a, c) = (vertices[from], vertices[to]);
+            let middle = evaluate(a, control, c, 0.5);
+            let side = 16.0 * epsilon * normal(c - a);
+            match (inside(middle + side), inside(middle - side)) {
+                (true, false) => boundary.push((from, to, control)),
+                (false, true) => boundary.push((to, from, control)),
+                _ => {}
+            }
+        }
+
+        // Link the edges into contours. At each vertex the contour takes the
+        // first edge clockwise from the one it arrived on, which traces the
+        // faces of the result one at a time.
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); vertices.len()];
+        for (index, &(from, _, _)) in boundary.iter().enumerate() {
+            outgoing[from].push(index);
+        }
+        let mut used = vec![false; boundary.len()];
+        for first in 0..boundary.len() {
+            if used[first] {
+                continue;
+            }
+            let start = boundary[first].0;
+            let (components, points) = (result.components.len(), result.points.len());
+            result.move_to(vertices[start]);
+            let mut current = first;
+            let complete = loop {
+                used[current] = true;
+                let (from, to, control) = boundary[current];
+                result.quadratic_to(control, vertices[to]);
+                if to == start {
+                    break true;
+                }
+
+                let back = Vec2::new(0.0, 0.0) - end_tangent(vertices[from], control, vertices[to]);
+                let next = outgoing[to]
+                    .iter()
+                    .copied()
+                    .filter(|&index| !used[index])
+                    .min_by(|&i, &j| {
+                        let angle = |index: usize| {
+                            let (from, to, control) = boundary[index];
+                            let direction = start_tangent(vertices[from], control, vertices[to]);
+                            let angle = -back.cross(direction).atan2(back.dot(direction));
+                            if angle <= 0.0 {
+                                angle + 2.0 * std::f32::consts::PI
+                            } else {
+                                angle
+                            }
+                        };
+                        angle(i).total_cmp(&angle(j))
+                    });
+                match next {
+                    Some(next) => current = next,
+                    None => break false,
+                }
+            };
+
+            // Every vertex has as many boundary edges leaving as arriving, but
+            // near-tangent input can misclassify an edge and strand the trace.
+            // Drop the partial contour rather than closing it across the gap.
+            if complete {
+                result.close();
+            } else {
+                result.components.truncate(components);
+                result.points.truncate(points);
+            }
+        }
+
+        result
+    }
+
+    /// Returns the segments of every contour, closed and split at their
+    /// extrema. Lines get their control point at the middle.
+    fn monotonic_segments(&self) -> Vec<Segment> {
+        let mut monotonic = Vec::new();
+        for component in self.components.iter() {
+            let points = &self.points[component.start..component.end];
+            let mut contour = segments(points);
+            let (first, last) = (points[0], points[points.len() - 1]);
+            if first != last {
+                contour.push((last, last, first));
+            }
+            for (a, b, c) in contour {
+                let segment = if b == a || b == c {
+                    (a, Vec2::lerp(0.5, a, c), c)
+                } else {
+                    (a, b, c)
+                };
+                let mut ts = extrema(segment.0, segment.1, segment.2);
+                ts.sort_by(f32::total_cmp);
+                let mut start = 0.0;
+                for t in ts.into_iter().chain([1.0]) {
+                    monotonic.push(subsegment(segment, start, t));
+                    start = t;
+                }
+            }
+        }
+        monotonic
+    }
+
+    /// Builds the path, bounded tightly by its curves rather than by their
+    /// control points.
+    ///
+    /// Segments whose control point falls outside those bounds are split at
+    /// their extrema, since the control point of a monotonic segment always
+    /// lies within the bounds of its endpoints. This keeps every encoded
+    /// point within range.
+    ///
+    /// Open contours are closed with a line, since they enclose the same
+    /// area when filled.
     pub fn build(&self) -> Path {
         let mut min = self.points.get(0).copied().unwrap_or(Vec2::new(0.0, 0.0));
         let mut max = self.points.get(0).copied().unwrap_or(Vec2::new(0.0, 0.0));
 
-        for &point in self.points.iter() {
-            min = min.min(point);
-            max = max.max(point);
+        for component in self.components.iter() {
+            let mut i = component.start;
+            min = min.min(self.points[i]);
+            max = max.max(self.points[i]);
+            while i + 2 < component.end {
+                let (p1, p2, p3) = (self.points[i], self.points[i + 1], self.points[i + 2]);
+                for t in extrema(p1, p2, p3) {
+                    let point = evaluate(p1, p2, p3, t);
+                    min = min.min(point);
+                    max = max.max(point);
+                }
+                min = min.min(p3);
+                max = max.max(p3);
+                i += 2;
+            }
         }
 
+        let inside = |point: Vec2| {
+            point.x >= min.x && point.x <= max.x && point.y >= min.y && point.y <= max.y
+        };
+
+        let mut split_points = Vec::with_capacity(self.points.len());
         let mut components = Vec::with_capacity(self.components.len() * 2);
         for component in self.components.iter() {
+            let start = split_points.len();
+
+            let mut i = component.start;
+            if i < component.end {
+                split_points.push(self.points[i]);
+            }
+            while i + 2 < component.end {
+                let (p1, p2, p3) = (self.points[i], self.points[i + 1], self.points[i + 2]);
+                if inside(p2) {
+                    split_points.extend_from_slice(&[p2, p3]);
+                } else {
+                    let mut ts = extrema(p1, p2, p3);
+                    ts.sort_by(f32::total_cmp);
+
+                    let mut prev = 0.0;
+                    let mut rest = (p1, p2, p3);
+                    for t in ts {
+                        // Map the split into the parameter range of the rest.
+                        let u = (t - prev) / (1.0 - prev);
+                        let (q1, q2, q3) = rest;
+                        let q12 = Vec2::lerp(u, q1, q2);
+                        let q23 = Vec2::lerp(u, q2, q3);
+                        let q = Vec2::lerp(u, q12, q23);
+                        split_points.extend_from_slice(&[q12, q]);
+                        rest = (q, q23, q3);
+                        prev = t;
+                    }
+                    split_points.extend_from_slice(&[rest.1, rest.2]);
+                }
+                i += 2;
+            }
+
+            // Filling closes open contours with a line.
+            let first = self.points[component.start];
+            if split_points.len() > start + 1 && split_points[split_points.len() - 1] != first {
+                split_points.extend_from_slice(&[first, first]);
+            }
+
             components.extend_from_slice(&[
-                component.start.try_into().unwrap(),
-                component.end.try_into().unwrap(),
+                start.try_into().unwrap(),
+                split_points.len().try_into().unwrap(),
             ]);
         }
 
         fn to_u16_unorm(value: f32, min: f32, max: f32) -> u16 {
-            (std::u16::MAX as f32 * ((value - min) / (max - min))).round() as u16
+            (u16::MAX as f32 * ((value - min) / (max - min)).clamp(0.0, 1.0)).round() as u16
         }
 
-        let mut points = Vec::with_capacity(self.points.len() * 2);
-        for point in self.points.iter() {
+        let mut points = Vec::with_capacity(split_points.len() * 2);
+        for point in split_points.iter() {
             points.extend_from_slice(&[
                 to_u16_unorm(point.x, min.x, max.x),
                 to_u16_unorm(point.y, min.y, max.y),
@@ -129,15 +1340,986 @@ impl PathBuilder {
         Path {
             min,
             max,
+            fill_rule: self.fill_rule,
             components,
             points,
         }
     }
 }
 
+/// A quadratic segment given by its start, control and end points.
+type Segment = (Vec2, Vec2, Vec2);
+
+/// Splits the points of a contour into its non-degenerate segments.
+fn segments(points: &[Vec2]) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i + 2 < points.len() {
+        let (a, b, c) = (points[i], points[i + 1], points[i + 2]);
+        if a != b || b != c {
+            segments.push((a, b, c));
+        }
+        i += 2;
+    }
+    segments
+}
+
+/// Arc length parameterization of a quadratic segment, from a table of the
+/// length up to evenly spaced parameters.
+struct ArcLength {
+    a: Vec2,
+    b: Vec2,
+    c: Vec2,
+    lengths: [f32; ArcLength::INTERVALS + 1],
+}
+
+impl ArcLength {
+    const INTERVALS: usize = 16;
+
+    fn new(a: Vec2, b: Vec2, c: Vec2) -> ArcLength {
+        let mut arc = ArcLength {
+            a,
+            b,
+            c,
+            lengths: [0.0; ArcLength::INTERVALS + 1],
+        };
+        for i in 0..ArcLength::INTERVALS {
+            let (t0, t1) = (arc.interval(i), arc.interval(i + 1));
+            arc.lengths[i + 1] = arc.lengths[i] + arc.integrate(t0, t1);
+        }
+        arc
+    }
+
+    fn interval(&self, i: usize) -> f32 {
+        i as f32 / ArcLength::INTERVALS as f32
+    }
+
+    fn length(&self) -> f32 {
+        self.lengths[ArcLength::INTERVALS]
+    }
+
+    fn point(&self, t: f32) -> Vec2 {
+        evaluate(self.a, self.b, self.c, t)
+    }
+
+    /// Returns the magnitude of the derivative at `t`.
+    fn speed(&self, t: f32) -> f32 {
+        2.0 * Vec2::lerp(t, self.b - self.a, self.c - self.b).length()
+    }
+
+    /// Integrates the speed from `t0` to `t1` by three point Gauss-Legendre
+    /// quadrature.
+    fn integrate(&self, t0: f32, t1: f32) -> f32 {
+        let (middle, half) = (0.5 * (t0 + t1), 0.5 * (t1 - t0));
+        let node = half * 0.6f32.sqrt();
+        half * (5.0 * self.speed(middle - node)
+            + 8.0 * self.speed(middle)
+            + 5.0 * self.speed(middle + node))
+            / 9.0
+    }
+
+    /// Returns the parameter at which the length from the start reaches
+    /// `distance`, interpolating in the table and refining with Newton's
+    /// method.
+    fn parameter(&self, distance: f32) -> f32 {
+        if distance <= 0.0 {
+            return 0.0;
+        }
+        if distance >= self.length() {
+            return 1.0;
+        }
+
+        let i = self.lengths[1..].partition_point(|&length| length < distance);
+        let (low, high) = (self.interval(i), self.interval(i + 1));
+        let span = self.lengths[i + 1] - self.lengths[i];
+        let fraction = if span > 0.0 {
+            (distance - self.lengths[i]) / span
+        } else {
+            0.0
+        };
+
+        let mut t = low + fraction * (high - low);
+        for _ in 0..4 {
+            let speed = self.speed(t);
+            if speed <= 1e-12 {
+                break;
+            }
+            let error = self.lengths[i] + self.integrate(low, t) - distance;
+            t = (t - error / speed).clamp(low, high);
+        }
+        t
+    }
+
+    fn subsegment(&self, t0: f32, t1: f32) -> Segment {
+        subsegment((self.a, self.b, self.c), t0, t1)
+    }
+}
+
+/// Returns the part of `segment` between the parameters `t0` and `t1`.
+fn subsegment((a, b, c): Segment, t0: f32, t1: f32) -> Segment {
+    let start = evaluate(a, b, c, t0);
+    let control = start + (t1 - t0) * Vec2::lerp(t0, b - a, c - b);
+    (start, control, evaluate(a, b, c, t1))
+}
+
+/// Returns the direction of the quadratic `a, b, c` at its start.
+fn start_tangent(a: Vec2, b: Vec2, c: Vec2) -> Vec2 {
+    if b != a {
+        b - a
+    } else {
+        c - a
+    }
+}
+
+/// Returns the direction of the quadratic `a, b, c` at its end.
+fn end_tangent(a: Vec2, b: Vec2, c: Vec2) -> Vec2 {
+    if c != b {
+        c - b
+    } else {
+        c - a
+    }
+}
+
+/// Returns the unit normal to the left of `tangent`.
+fn normal(tangent: Vec2) -> Vec2 {
+    Vec2::new(-tangent.y, tangent.x).normalized()
+}
+
+/// Returns the point at `t` on the quadratic with the given control points.
+fn evaluate(p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    Vec2::lerp(t, Vec2::lerp(t, p1, p2), Vec2::lerp(t, p2, p3))
+}
+
+/// Returns the parameters strictly between 0 and 1 at which the quadratic
+/// with the given control points reaches a horizontal or vertical extremum.
+fn extrema(p1: Vec2, p2: Vec2, p3: Vec2) -> Vec<f32> {
+    let mut ts = Vec::new();
+    for (a, b, c) in [(p1.x, p2.x, p3.x), (p1.y, p2.y, p3.y)] {
+        let denominator = a - 2.0 * b + c;
+        if denominator != 0.0 {
+            let t = (a - b) / denominator;
+            if t > 0.0 && t < 1.0 {
+                ts.push(t);
+            }
+        }
+    }
+    ts
+}
+
+/// Returns whether the bounds of two monotonic segments, grown by
+/// `epsilon`, overlap.
+fn bounds_overlap((a1, _, c1): Segment, (a2, _, c2): Segment, epsilon: f32) -> bool {
+    let (min1, max1) = (a1.min(c1), a1.max(c1));
+    let (min2, max2) = (a2.min(c2), a2.max(c2));
+    min1.x <= max2.x + epsilon
+        && min2.x <= max1.x + epsilon
+        && min1.y <= max2.y + epsilon
+        && min2.y <= max1.y + epsilon
+}
+
+/// Finds the crossings of the monotonic segments `s1` and `s2` within the
+/// given parameter ranges, by splitting them until they are flat and then
+/// intersecting their chords. The results are refined with Newton's method.
+/// Parallel chords are skipped, since overlapping segments are split where
+/// their endpoints touch instead.
+fn intersect(
+    s1: Segment,
+    range1: (f32, f32),
+    s2: Segment,
+    range2: (f32, f32),
+    epsilon: f32,
+    depth: usize,
+    crossings: &mut Vec<(f32, f32)>,
+) {
+    const MAX_DEPTH: usize = 24;
+
+    let (p, q) = (
+        subsegment(s1, range1.0, range1.1),
+        subsegment(s2, range2.0, range2.1),
+    );
+    if !bounds_overlap(p, q, epsilon) {
+        return;
+    }
+
+    let flatness = |(a, b, c): Segment| (b - Vec2::lerp(0.5, a, c)).length();
+    let (flat1, flat2) = (flatness(p) <= epsilon, flatness(q) <= epsilon);
+    if (flat1 && flat2) || depth >= MAX_DEPTH {
+        let (d1, d2) = (p.2 - p.0, q.2 - q.0);
+        let denominator = d1.cross(d2);
+        if denominator.abs() <= 1e-4 * d1.length() * d2.length() {
+            return;
+        }
+        let s = (q.0 - p.0).cross(d2) / denominator;
+        let u = (q.0 - p.0).cross(d1) / denominator;
+        if !(0.0..=1.0).contains(&s) || !(0.0..=1.0).contains(&u) {
+            return;
+        }
+
+        let mut t1 = range1.0 + s * (range1.1 - range1.0);
+        let mut t2 = range2.0 + u * (range2.1 - range2.0);
+        let derivative = |(a, b, c): Segment, t: f32| 2.0 * Vec2::lerp(t, b - a, c - b);
+        for _ in 0..3 {
+            let error = evaluate(s1.0, s1.1, s1.2, t1) - evaluate(s2.0, s2.1, s2.2, t2);
+            let (j1, j2) = (derivative(s1, t1), derivative(s2, t2));
+            let determinant = j2.cross(j1);
+            if determinant.abs() <= 1e-12 {
+                break;
+            }
+            // Solve j1 * dt1 - j2 * dt2 = -error.
+            t1 = (t1 - j2.cross(error) / determinant).clamp(range1.0, range1.1);
+            t2 = (t2 - j1.cross(error) / determinant).clamp(range2.0, range2.1);
+        }
+        crossings.push((t1, t2));
+        return;
+    }
+
+    let middle1 = 0.5 * (range1.0 + range1.1);
+    let middle2 = 0.5 * (range2.0 + range2.1);
+    if flat2 || (!flat1 && range1.1 - range1.0 >= range2.1 - range2.0) {
+        for half in [(range1.0, middle1), (middle1, range1.1)] {
+            intersect(s1, half, s2, range2, epsilon, depth + 1, crossings);
+        }
+    } else {
+        for half in [(range2.0, middle2), (middle2, range2.1)] {
+            intersect(s1, range1, s2, half, epsilon, depth + 1, crossings);
+        }
+    }
+}
+
+/// Returns the parameter at which `point` lies on the interior of
+/// `segment`, if it's within `epsilon` of it.
+fn touch(segment: Segment, point: Vec2, epsilon: f32) -> Option<f32> {
+    const SAMPLES: usize = 16;
+
+    let (a, b, c) = segment;
+    if !bounds_overlap(segment, (point, point, point), epsilon)
+        || (point - a).length() <= 2.0 * epsilon
+        || (point - c).length() <= 2.0 * epsilon
+    {
+        return None;
+    }
+
+    let distance = |t: f32| (evaluate(a, b, c, t) - point).length();
+    let mut t = (0..=SAMPLES)
+        .map(|i| i as f32 / SAMPLES as f32)
+        .min_by(|&t1, &t2| distance(t1).total_cmp(&distance(t2)))
+        .unwrap();
+    // Newton's method on the derivative of the squared distance.
+    for _ in 0..4 {
+        let offset = evaluate(a, b, c, t) - point;
+        let derivative = 2.0 * Vec2::lerp(t, b - a, c - b);
+        let second = 2.0 * (a - 2.0 * b + c);
+        let slope = derivative.dot(derivative) + offset.dot(second);
+        if slope.abs() <= 1e-12 {
+            break;
+        }
+        t = (t - offset.dot(derivative) / slope).clamp(0.0, 1.0);
+    }
+
+    (distance(t) <= epsilon).then_some(t)
+}
+
+/// Returns the winding number of the closed contours made of the monotonic
+/// `segments` around `point`, counting crossings of a ray to the right.
+fn winding(segments: &[Segment], point: Vec2) -> i32 {
+    let mut winding = 0;
+    for &(a, b, c) in segments {
+        let upward = c.y > a.y;
+        let (low, high) = if upward { (a.y, c.y) } else { (c.y, a.y) };
+        if point.y < low || point.y >= high {
+            continue;
+        }
+
+        // Solve for the parameter where the segment reaches `point.y`.
+        let qa = a.y - 2.0 * b.y + c.y;
+        let qb = b.y - a.y;
+        let qc = a.y - point.y;
+        let t = if qa.abs() <= 1e-6 * (qb.abs() + qc.abs()) {
+            -qc / (2.0 * qb)
+        } else {
+            let root = (qb * qb - qa * qc).max(0.0).sqrt();
+            let t = (-qb + root) / qa;
+            if (0.0..=1.0).contains(&t) {
+                t
+            } else {
+                (-qb - root) / qa
+            }
+        };
+
+        if evaluate(a, b, c, t.clamp(0.0, 1.0)).x > point.x {
+            winding += if upward { 1 } else { -1 };
+        }
+    }
+    winding
+}
+
 pub struct Path {
     pub min: Vec2,
     pub max: Vec2,
+    pub fill_rule: FillRule,
     pub components: Vec<u16>,
     pub points: Vec<u16>,
 }
+
+impl Path {
+    /// Returns the point at the given index, mapped back from the encoded
+    /// range to path coordinates.
+    pub fn point(&self, index: usize) -> Vec2 {
+        let unorm = |value: u16| value as f32 / u16::MAX as f32;
+        let size = self.max - self.min;
+        Vec2::new(
+            self.min.x + size.x * unorm(self.points[2 * index]),
+            self.min.y + size.y * unorm(self.points[2 * index + 1]),
+        )
+    }
+
+    /// Returns the horizontal extent of the outline within the band between
+    /// `y_min` and `y_max`, or `None` if the outline does not enter it.
+    ///
+    /// Each quadratic segment is approximated by a polyline, which is
+    /// accurate enough for placing gaps in decoration lines.
+    pub fn horizontal_extent(&self, y_min: f32, y_max: f32) -> Option<(f32, f32)> {
+        const STEPS: usize = 16;
+
+        let mut extent: Option<(f32, f32)> = None;
+        let mut include = |x: f32| {
+            extent = Some(match extent {
+                Some((min, max)) => (min.min(x), max.max(x)),
+                None => (x, x),
+            });
+        };
+
+        for component in self.components.chunks(2) {
+            let (start, end) = (component[0] as usize, component[1] as usize);
+            let mut i = start;
+            while i + 2 < end {
+                let p1 = self.point(i);
+                let p2 = self.point(i + 1);
+                let p3 = self.point(i + 2);
+
+                let mut prev = p1;
+                for step in 1..=STEPS {
+                    let t = step as f32 / STEPS as f32;
+                    let next = Vec2::lerp(t, Vec2::lerp(t, p1, p2), Vec2::lerp(t, p2, p3));
+
+                    // Clip the chord to the band and include what remains.
+                    let (a, b) = if prev.y <= next.y {
+                        (prev, next)
+                    } else {
+                        (next, prev)
+                    };
+                    if b.y >= y_min && a.y <= y_max {
+                        let at = |y: f32| {
+                            if b.y == a.y {
+                                a.x
+                            } else {
+                                a.x + (b.x - a.x) * ((y - a.y) / (b.y - a.y)).clamp(0.0, 1.0)
+                            }
+                        };
+                        include(at(y_min.max(a.y)));
+                        include(at(y_max.min(b.y)));
+                    }
+
+                    prev = next;
+                }
+
+                i += 2;
+            }
+        }
+
+        extent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns points along every segment of the builder, `samples` per
+    /// segment, as a polyline per contour.
+    fn polylines(builder: &PathBuilder, samples: usize) -> Vec<Vec<Vec2>> {
+        let mut polylines = Vec::new();
+        for component in builder.components.iter() {
+            let points = &builder.points[component.start..component.end];
+            let mut polyline = vec![points[0]];
+            for window in points.windows(3).step_by(2) {
+                for i in 1..=samples {
+                    let t = i as f32 / samples as f32;
+                    polyline.push(evaluate(window[0], window[1], window[2], t));
+                }
+            }
+            polylines.push(polyline);
+        }
+        polylines
+    }
+
+    fn distance_to_polyline(point: Vec2, polyline: &[Vec2]) -> f32 {
+        polyline
+            .windows(2)
+            .map(|pair| {
+                let (a, b) = (pair[0], pair[1]);
+                let ab = b - a;
+                let length = ab.dot(ab);
+                let t = if length > 0.0 {
+                    ((point - a).dot(ab) / length).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                (a + t * ab - point).length()
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    fn cubic(p1: Vec2, p2: Vec2, p3: Vec2, p4: Vec2, t: f32) -> Vec2 {
+        let s = 1.0 - t;
+        (s * s * s) * p1 + (3.0 * s * s * t) * p2 + (3.0 * s * t * t) * p3 + (t * t * t) * p4
+    }
+
+    /// Returns the largest distance between the cubic and its quadratic
+    /// approximation under `tolerance`, measured both ways.
+    fn cubic_deviation(points: [Vec2; 4], tolerance: Tolerance) -> f32 {
+        let [p1, p2, p3, p4] = points;
+        let mut builder = PathBuilder::new();
+        builder.tolerance(tolerance);
+        builder.move_to(p1).cubic_to(p2, p3, p4);
+        assert!(builder.points.len() > 3, "cubic should be split");
+
+        const SAMPLES: usize = 1000;
+        let curve: Vec<Vec2> = (0..=SAMPLES)
+            .map(|i| cubic(p1, p2, p3, p4, i as f32 / SAMPLES as f32))
+            .collect();
+        let approximation = polylines(&builder, 64).remove(0);
+
+        let to_approximation = curve
+            .iter()
+            .map(|&point| distance_to_polyline(point, &approximation));
+        let to_curve = approximation
+            .iter()
+            .map(|&point| distance_to_polyline(point, &curve));
+        to_approximation.chain(to_curve).fold(0.0, f32::max)
+    }
+
+    const CUBICS: [[(f32, f32); 4]; 4] = [
+        // A quarter circle.
+        [
+            (1000.0, 0.0),
+            (1000.0, 552.0),
+            (552.0, 1000.0),
+            (0.0, 1000.0),
+        ],
+        // An S curve.
+        [(0.0, 0.0), (800.0, 0.0), (-200.0, 600.0), (600.0, 600.0)],
+        // A loop.
+        [(0.0, 0.0), (900.0, 600.0), (-300.0, 600.0), (600.0, 0.0)],
+        // A near cusp.
+        [(0.0, 0.0), (700.0, 500.0), (0.0, 500.0), (700.0, 0.0)],
+    ];
+
+    fn check_cubics(tolerance: Tolerance) {
+        for points in CUBICS {
+            let points = points.map(|(x, y)| Vec2::new(x, y));
+            let deviation = cubic_deviation(points, tolerance);
+            assert!(
+                deviation <= tolerance.distance(),
+                "deviation {} exceeds {:?}",
+                deviation,
+                tolerance
+            );
+        }
+    }
+
+    #[test]
+    fn stroke_joins_only_closed_contours() {
+        let triangle = |close: bool| {
+            let mut builder = PathBuilder::new();
+            builder
+                .move_to(Vec2::new(0.0, 0.0))
+                .line_to(Vec2::new(10.0, 0.0))
+                .line_to(Vec2::new(0.0, 10.0))
+                .line_to(Vec2::new(0.0, 0.0));
+            if close {
+                builder.close();
+            }
+            builder
+        };
+
+        // Ending where it started does not close a contour.
+        let (open, closed) = (triangle(false), triangle(true));
+        assert!(!open.components[0].closed);
+        assert!(closed.components[0].closed);
+
+        // An open contour returning to its start is outlined once, with
+        // caps, while a closed one gets an outline for each side.
+        let style = StrokeStyle::new(1.0);
+        let (open, closed) = (open.stroke(&style), closed.stroke(&style));
+        assert_eq!(open.components.len(), 1);
+        assert_eq!(closed.components.len(), 2);
+        assert!(closed.components.iter().all(|component| component.closed));
+
+        // Only the closed contour gets a mitered corner at its start.
+        let miter = Vec2::new(-0.5, -0.5);
+        let has_miter = |builder: &PathBuilder| {
+            builder
+                .points
+                .iter()
+                .any(|point| point.distance(miter) < 1e-4)
+        };
+        assert!(has_miter(&closed));
+        assert!(!has_miter(&open));
+    }
+
+    #[test]
+    fn dash_pieces() {
+        let mut line = PathBuilder::new();
+        line.move_to(Vec2::new(0.0, 0.0))
+            .line_to(Vec2::new(10.0, 0.0));
+        // A gap ending at the end of the contour leaves no dot there.
+        assert_eq!(line.dash(&[5.0, 5.0], 0.0).components.len(), 1);
+        assert_eq!(line.dash(&[2.5], 0.0).components.len(), 2);
+        // Zero length dashes become lone points.
+        let dots = line.dash(&[0.0, 4.0], 0.0);
+        assert_eq!(dots.components.len(), 3);
+        assert!(dots.components.iter().all(|c| c.end - c.start == 1));
+
+        let mut square = PathBuilder::new();
+        square.rect(Vec2::new(0.0, 0.0), Vec2::new(10.0, 10.0));
+        assert_eq!(square.dash(&[4.0, 2.0], 0.0).components.len(), 7);
+        // The dash through the start is joined with the one at the end.
+        assert_eq!(square.dash(&[3.0, 2.0], 1.0).components.len(), 8);
+        // A dash longer than the contour keeps it closed.
+        let solid = square.dash(&[100.0], 0.0);
+        assert_eq!(solid.components.len(), 1);
+        assert!(solid.components[0].closed);
+    }
+
+    #[test]
+    fn embolden_joins_only_closed_contours() {
+        let outline = |close: bool| {
+            let mut builder = PathBuilder::new();
+            builder
+                .move_to(Vec2::new(0.0, 0.0))
+                .line_to(Vec2::new(10.0, 0.0))
+                .line_to(Vec2::new(10.0, 10.0))
+                .line_to(Vec2::new(0.0, 10.0))
+                .line_to(Vec2::new(0.0, 0.0));
+            if close {
+                builder.close();
+            }
+            builder.embolden(1.0);
+            builder
+        };
+
+        // The corner at the start of a closed contour is mitered, so the
+        // contour still ends where it starts.
+        let closed = outline(true);
+        let points = &closed.points;
+        assert_eq!(points[0], Vec2::new(-1.0, -1.0));
+        assert_eq!(points[points.len() - 1], Vec2::new(-1.0, -1.0));
+        assert_area(&closed, 144.0);
+
+        // The ends of an open contour returning to its start move apart.
+        let open = outline(false);
+        let points = &open.points;
+        assert_eq!(points[0], Vec2::new(0.0, -1.0));
+        assert_eq!(points[points.len() - 1], Vec2::new(-1.0, 0.0));
+    }
+
+    /// Decodes a built path into the same polylines as `polylines`.
+    fn built_polylines(path: &Path, samples: usize) -> Vec<Vec<Vec2>> {
+        let mut polylines = Vec::new();
+        for component in path.components.chunks(2) {
+            let (start, end) = (component[0] as usize, component[1] as usize);
+            let points: Vec<Vec2> = (start..end).map(|i| path.point(i)).collect();
+            let mut polyline = vec![points[0]];
+            for window in points.windows(3).step_by(2) {
+                for i in 1..=samples {
+                    let t = i as f32 / samples as f32;
+                    polyline.push(evaluate(window[0], window[1], window[2], t));
+                }
+            }
+            polylines.push(polyline);
+        }
+        polylines
+    }
+
+    #[test]
+    fn build_bounds_curves_tightly() {
+        let mut builder = PathBuilder::new();
+        builder
+            .move_to(Vec2::new(0.0, 0.0))
+            .quadratic_to(Vec2::new(5.0, 10.0), Vec2::new(10.0, 0.0))
+            .quadratic_to(Vec2::new(14.0, -3.0), Vec2::new(10.0, -6.0))
+            .close();
+        let path = builder.build();
+
+        // The bounds hold the extrema of the curves, not their control points.
+        assert!(path.min.distance(Vec2::new(0.0, -6.0)) < 1e-4);
+        assert!(path.max.distance(Vec2::new(12.0, 5.0)) < 1e-4);
+
+        // Both curves are split at their extrema, with every piece lying on
+        // the original and the pieces together covering all of it.
+        assert_eq!(path.components, vec![0, 11]);
+        let tolerance = 1e-3 * (path.max - path.min).length();
+        let original = &polylines(&builder, 64)[0];
+        let split = &built_polylines(&path, 64)[0];
+        for &point in split.iter() {
+            assert!(distance_to_polyline(point, original) < tolerance);
+        }
+        for &point in original.iter() {
+            assert!(distance_to_polyline(point, split) < tolerance);
+        }
+    }
+
+    /// Asserts that every sampled point of `builder` after the first
+    /// `skip` lies on the circle within the builder's tolerance.
+    fn assert_on_circle(builder: &PathBuilder, center: Vec2, radius: f32, skip: usize) {
+        let tolerance = builder.tolerance.distance();
+        for &point in polylines(builder, 16)[0].iter().skip(skip) {
+            let error = (point.distance(center) - radius).abs();
+            assert!(
+                error <= tolerance,
+                "{:?} is {} off the circle",
+                point,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn arc_ends_exactly_at_its_endpoint() {
+        let mut builder = PathBuilder::new();
+        builder.tolerance(Tolerance::Absolute(0.01));
+        builder.move_to(Vec2::new(0.0, 0.0)).arc_to(
+            Vec2::new(5.0, 5.0),
+            0.0,
+            false,
+            true,
+            Vec2::new(10.0, 0.0),
+        );
+        assert_eq!(builder.points.last(), Some(&Vec2::new(10.0, 0.0)));
+        assert_on_circle(&builder, Vec2::new(5.0, 0.0), 5.0, 0);
+
+        let end = Vec2::new(-3.0, 7.0);
+        builder.arc_to(Vec2::new(4.0, 9.0), 0.5, true, false, end);
+        assert_eq!(builder.points.last(), Some(&end));
+    }
+
+    #[test]
+    fn arc_scales_up_radii_too_small_for_the_endpoint() {
+        let mut builder = PathBuilder::new();
+        builder.tolerance(Tolerance::Absolute(0.01));
+        builder.move_to(Vec2::new(0.0, 0.0)).arc_to(
+            Vec2::new(1.0, 1.0),
+            0.0,
+            false,
+            true,
+            Vec2::new(10.0, 0.0),
+        );
+        // The radius grows to half the distance, giving a half circle.
+        assert_on_circle(&builder, Vec2::new(5.0, 0.0), 5.0, 0);
+        let top = polylines(&builder, 16)[0]
+            .iter()
+            .map(|point| point.y.abs())
+            .fold(0.0, f32::max);
+        assert!((top - 5.0).abs() <= 0.01);
+    }
+
+    #[test]
+    fn tangent_arc_rounds_corners_and_skips_collinear_points() {
+        let mut builder = PathBuilder::new();
+        builder.tolerance(Tolerance::Absolute(0.01));
+        builder.move_to(Vec2::new(0.0, 0.0)).tangent_arc_to(
+            Vec2::new(10.0, 0.0),
+            Vec2::new(10.0, 10.0),
+            2.0,
+        );
+        let points = &builder.points;
+        assert_eq!(points[2], Vec2::new(8.0, 0.0));
+        assert!(points[points.len() - 1].distance(Vec2::new(10.0, 2.0)) < 1e-4);
+        // Skip the line leading up to the arc.
+        assert_on_circle(&builder, Vec2::new(8.0, 2.0), 2.0, 17);
+
+        // Points in line with the corner, ahead or behind, leave no room for
+        // an arc, so the corner is reached with a line.
+        for point in [Vec2::new(10.0, 0.0), Vec2::new(2.0, 0.0)] {
+            let mut builder = PathBuilder::new();
+            builder
+                .move_to(Vec2::new(0.0, 0.0))
+                .tangent_arc_to(Vec2::new(5.0, 0.0), point, 2.0);
+            assert_eq!(
+                builder.iter().collect::<Vec<_>>(),
+                vec![
+                    PathSegment::MoveTo(Vec2::new(0.0, 0.0)),
+                    PathSegment::Line(Vec2::new(5.0, 0.0)),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn rounded_rect_area() {
+        let (w, h) = (20.0, 10.0);
+        let tolerance = 0.01;
+        // The second set of radii don't fit and are scaled down to 5.
+        for (radius, fitted) in [(3.0, 3.0), (8.0, 5.0)] {
+            let mut builder = PathBuilder::new();
+            builder.tolerance(Tolerance::Absolute(tolerance));
+            builder.rounded_rect(
+                Vec2::new(0.0, 0.0),
+                Vec2::new(w, h),
+                [Vec2::new(radius, radius); 4],
+            );
+            let expected = w * h - (4.0 - std::f32::consts::PI) * fitted * fitted;
+            let slack = 2.0 * (w + h) * tolerance;
+            let area = builder.signed_area();
+            assert!((area - expected).abs() <= slack, "{} != {}", area, expected);
+        }
+    }
+
+    fn square(min: (f32, f32), max: (f32, f32)) -> PathBuilder {
+        let mut builder = PathBuilder::new();
+        builder.rect(Vec2::new(min.0, min.1), Vec2::new(max.0, max.1));
+        builder
+    }
+
+    fn assert_area(builder: &PathBuilder, area: f32) {
+        let actual = builder.signed_area();
+        assert!((actual - area).abs() < 1e-3, "area {} != {}", actual, area);
+    }
+
+    #[test]
+    fn boolean_union_merges_shared_edge() {
+        let left = square((0.0, 0.0), (10.0, 10.0));
+        let right = square((10.0, 0.0), (20.0, 10.0));
+        let union = left.boolean(&right, BooleanOp::Union);
+        assert_eq!(union.contours(), 1);
+        assert_area(&union, 200.0);
+
+        // Only part of the edge is shared.
+        let offset = square((10.0, 5.0), (20.0, 15.0));
+        let union = left.boolean(&offset, BooleanOp::Union);
+        assert_eq!(union.contours(), 1);
+        assert_area(&union, 200.0);
+
+        let intersection = left.boolean(&right, BooleanOp::Intersection);
+        assert_eq!(intersection.contours(), 0);
+    }
+
+    #[test]
+    fn boolean_difference_cuts_hole() {
+        let outer = square((0.0, 0.0), (10.0, 10.0));
+        let inner = square((3.0, 3.0), (7.0, 7.0));
+        let difference = outer.boolean(&inner, BooleanOp::Difference);
+        assert_eq!(difference.contours(), 2);
+        assert_area(&difference, 84.0);
+        assert_ne!(difference.orientation(0), difference.orientation(1));
+
+        let overlapping = square((5.0, 5.0), (15.0, 15.0));
+        let difference = outer.boolean(&overlapping, BooleanOp::Difference);
+        assert_eq!(difference.contours(), 1);
+        assert_area(&difference, 75.0);
+    }
+
+    #[test]
+    fn boolean_xor_of_identical_shapes_is_empty() {
+        let a = square((0.0, 0.0), (10.0, 10.0));
+        let xor = a.boolean(&square((0.0, 0.0), (10.0, 10.0)), BooleanOp::Xor);
+        assert_eq!(xor.contours(), 0);
+
+        let mut circle = PathBuilder::new();
+        circle.circle(Vec2::new(0.0, 0.0), 10.0);
+        let xor = circle.boolean(&circle, BooleanOp::Xor);
+        assert_eq!(xor.contours(), 0);
+        let union = circle.boolean(&circle, BooleanOp::Union);
+        assert_eq!(union.contours(), 1);
+        assert_area(&union, circle.signed_area());
+    }
+
+    #[test]
+    fn boolean_shapes_touching_at_vertex() {
+        let a = square((0.0, 0.0), (10.0, 10.0));
+        let b = square((10.0, 10.0), (20.0, 20.0));
+        let union = a.boolean(&b, BooleanOp::Union);
+        assert_eq!(union.contours(), 2);
+        assert_area(&union, 200.0);
+        assert_eq!(union.orientation(0), Orientation::CounterClockwise);
+        assert_eq!(union.orientation(1), Orientation::CounterClockwise);
+
+        let intersection = a.boolean(&b, BooleanOp::Intersection);
+        assert_eq!(intersection.contours(), 0);
+    }
+
+    #[test]
+    fn boolean_near_tangent_operands() {
+        let mut circle = PathBuilder::new();
+        circle.circle(Vec2::new(0.0, 0.0), 10.0);
+        let area = circle.signed_area();
+
+        for step in 0..8 {
+            let angle = step as f32 * 0.0314;
+            for gap in [-1e-4, 0.0, 1e-4] {
+                let distance = 20.0 + gap;
+                let mut other = PathBuilder::new();
+                other.circle(
+                    Vec2::new(distance * angle.cos(), distance * angle.sin()),
+                    10.0,
+                );
+                let union = circle.boolean(&other, BooleanOp::Union);
+                assert!(union.signed_area() <= 2.0 * area + 0.1);
+                let intersection = circle.boolean(&other, BooleanOp::Intersection);
+                assert!(intersection.signed_area().abs() < 0.1);
+
+                let edge = 10.0 + gap;
+                let square = square((edge, -5.0 + step as f32 * 0.01), (edge + 10.0, 5.0));
+                let difference = circle.boolean(&square, BooleanOp::Difference);
+                assert!((difference.signed_area() - area).abs() < 0.1);
+            }
+        }
+    }
+
+    #[test]
+    fn mirroring_flips_orientation() {
+        let mut builder = square((0.0, 0.0), (10.0, 10.0));
+        assert_eq!(builder.orientation(0), Orientation::CounterClockwise);
+
+        builder.transform(&Mat2x3::scale(1.0, -1.0));
+        assert_eq!(builder.orientation(0), Orientation::Clockwise);
+        assert_area(&builder, -100.0);
+        builder.reverse();
+        assert_eq!(builder.orientation(0), Orientation::CounterClockwise);
+
+        builder.transform(&(Mat2x3::rotate(1.0) * Mat2x3::scale(-2.0, 1.0)));
+        assert_eq!(builder.orientation(0), Orientation::Clockwise);
+        assert_area(&builder, -200.0);
+    }
+
+    #[test]
+    fn rotate_sideways_is_exact() {
+        let mut builder = square((1.0, 2.0), (3.0, 5.0));
+        let expected: Vec<Vec2> = builder
+            .points
+            .iter()
+            .map(|p| Vec2::new(p.y, -p.x))
+            .collect();
+        builder.rotate_sideways();
+        assert_eq!(builder.points, expected);
+        assert_eq!(builder.orientation(0), Orientation::CounterClockwise);
+    }
+
+    fn mixed_path() -> PathBuilder {
+        let mut builder = PathBuilder::new();
+        builder
+            .move_to(Vec2::new(0.0, 0.0))
+            .line_to(Vec2::new(10.0, 0.0))
+            .quadratic_to(Vec2::new(15.0, 5.0), Vec2::new(10.0, 10.0))
+            .close();
+        builder
+            .move_to(Vec2::new(20.0, 0.0))
+            .quadratic_to(Vec2::new(20.0, 0.0), Vec2::new(25.0, 5.0))
+            .line_to(Vec2::new(20.0, 0.0));
+        builder.move_to(Vec2::new(30.0, 30.0));
+        builder.circle(Vec2::new(50.0, 0.0), 5.0);
+        builder
+    }
+
+    fn round_trip(builder: &PathBuilder) -> PathBuilder {
+        let mut copy = PathBuilder::new();
+        for segment in builder.iter() {
+            copy.push_segment(segment);
+        }
+        copy
+    }
+
+    fn assert_same_contours(a: &PathBuilder, b: &PathBuilder) {
+        assert_eq!(a.points, b.points);
+        let ranges = |builder: &PathBuilder| -> Vec<(usize, usize, bool)> {
+            builder
+                .components
+                .iter()
+                .map(|c| (c.start, c.end, c.closed))
+                .collect()
+        };
+        assert_eq!(ranges(a), ranges(b));
+    }
+
+    #[test]
+    fn iter_round_trips() {
+        let builder = mixed_path();
+        assert_eq!(
+            builder.iter().take(5).collect::<Vec<_>>(),
+            vec![
+                PathSegment::MoveTo(Vec2::new(0.0, 0.0)),
+                PathSegment::Line(Vec2::new(10.0, 0.0)),
+                PathSegment::Quad(Vec2::new(15.0, 5.0), Vec2::new(10.0, 10.0)),
+                PathSegment::Line(Vec2::new(0.0, 0.0)),
+                PathSegment::Close,
+            ]
+        );
+        assert_same_contours(&builder, &round_trip(&builder));
+
+        let mut reversed = mixed_path();
+        reversed.reverse();
+        assert_same_contours(&reversed, &round_trip(&reversed));
+    }
+
+    #[test]
+    fn contour_areas() {
+        let mut unit = square((0.0, 0.0), (1.0, 1.0));
+        assert_area(&unit, 1.0);
+        unit.reverse();
+        assert_area(&unit, -1.0);
+
+        let radius = 10.0;
+        let mut circle = PathBuilder::new();
+        circle.circle(Vec2::new(3.0, -4.0), radius);
+        // Each quadratic stays within the tolerance of the circle.
+        let slack = 2.0 * std::f32::consts::PI * radius * circle.tolerance.distance();
+        let area = std::f32::consts::PI * radius * radius;
+        assert!((circle.contour_area(0) - area).abs() <= slack);
+
+        // Open contours are measured as if closed with a line.
+        let mut open = PathBuilder::new();
+        open.move_to(Vec2::new(0.0, 0.0))
+            .line_to(Vec2::new(2.0, 0.0))
+            .line_to(Vec2::new(2.0, 2.0));
+        assert!((open.contour_area(0) - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn reverse_contour_flips_orientation() {
+        let mut builder = mixed_path();
+        let areas: Vec<f32> = (0..builder.contours())
+            .map(|i| builder.contour_area(i))
+            .collect();
+        for (i, &area) in areas.iter().enumerate() {
+            let orientation = builder.orientation(i);
+            builder.reverse_contour(i);
+            if area != 0.0 {
+                assert_ne!(builder.orientation(i), orientation);
+            }
+            assert!((builder.contour_area(i) + area).abs() < 1e-3);
+        }
+
+        builder.reverse();
+        for (i, &area) in areas.iter().enumerate() {
+            assert!((builder.contour_area(i) - area).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn cubic_within_absolute_tolerance() {
+        check_cubics(Tolerance::Absolute(0.1));
+        check_cubics(Tolerance::Absolute(1.0));
+        check_cubics(Tolerance::Absolute(5.0));
+    }
+
+    #[test]
+    fn cubic_within_em_tolerance() {
+        for units_per_em in [1000.0, 2048.0] {
+            check_cubics(Tolerance::Em {
+                fraction: 0.001,
+                units_per_em,
+            });
+        }
+    }
+}
@@ -60,6 +60,7 @@ pub unsafe trait UniformFormat {
 
 #[allow(unused)]
 pub enum UniformType {
+    Uint,
     Float,
     Float2,
     Float3,
@@ -123,6 +124,9 @@ impl<U: UniformFormat, V: VertexFormat> Program<U, V> {
                 let ptr = (uniforms as *const U as *const c_void).offset(uniform.offset);
 
                 match uniform.type_ {
+                    UniformType::Uint => {
+                        gl::Uniform1uiv(location, 1, ptr as *const GLuint);
+                    }
                     UniformType::Float => {
                         gl::Uniform1fv(location, 1, ptr as *const GLfloat);
                     }
@@ -165,12 +169,22 @@ impl<U: UniformFormat, V: VertexFormat> Program<U, V> {
 
             gl::BindVertexArray(mesh.vao);
 
-            gl::DrawElements(
-                gl::TRIANGLES,
-                mesh.len.try_into().unwrap(),
-                gl::UNSIGNED_SHORT,
-                0 as *const GLvoid,
-            );
+            if let Some(instances) = mesh.instances {
+                gl::DrawElementsInstanced(
+                    gl::TRIANGLES,
+                    mesh.len.try_into().unwrap(),
+                    gl::UNSIGNED_SHORT,
+                    std::ptr::null(),
+                    instances.try_into().unwrap(),
+                );
+            } else {
+                gl::DrawElements(
+                    gl::TRIANGLES,
+                    mesh.len.try_into().unwrap(),
+                    gl::UNSIGNED_SHORT,
+                    0 as *const GLvoid,
+                );
+            }
         }
     }
 }
@@ -229,11 +243,26 @@ pub struct Mesh<V> {
     vbo: GLuint,
     ibo: GLuint,
     len: usize,
+    instances: Option<usize>,
+    size: usize,
     marker: PhantomData<V>,
 }
 
 impl<V: VertexFormat> Mesh<V> {
     pub fn new(vertices: &[V], indices: &[u16]) -> Self {
+        Self::with_divisor(vertices, indices, 0)
+    }
+
+    /// Constructs a mesh whose attributes advance once per instance rather
+    /// than once per vertex. The index buffer is drawn once for each element
+    /// of `instances`, so vertex positions must be derived from `gl_VertexID`.
+    pub fn instanced(instances: &[V], indices: &[u16]) -> Self {
+        let mut mesh = Self::with_divisor(instances, indices, 1);
+        mesh.instances = Some(instances.len());
+        mesh
+    }
+
+    fn with_divisor(vertices: &[V], indices: &[u16], divisor: GLuint) -> Self {
         let mut vbo: u32 = 0;
         let mut ibo: u32 = 0;
         let mut vao: u32 = 0;
@@ -292,6 +321,7 @@ impl<V: VertexFormat> Mesh<V> {
                         );
                     }
                 }
+                gl::VertexAttribDivisor(attrib.location.try_into().unwrap(), divisor);
             }
         }
 
@@ -300,11 +330,20 @@ impl<V: VertexFormat> Mesh<V> {
             vbo,
             ibo,
             len: indices.len(),
+            instances: None,
+            size: std::mem::size_of_val(vertices) + std::mem::size_of_val(indices),
             marker: PhantomData,
         }
     }
 }
 
+impl<V> Mesh<V> {
+    /// Returns the combined size in bytes of the vertex and index buffers.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
 impl<V> Drop for Mesh<V> {
     fn drop(&mut self) {
         unsafe {
@@ -0,0 +1,1175 @@
+use std::fmt;
+
+use crate::geom::*;
+use crate::path::*;
+
+/// An error in SVG input, with the byte offset at which it was found.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(position: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Reads the numbers, flags and commands of SVG path data.
+struct Tokens<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(data: &'a str) -> Tokens<'a> {
+        Tokens {
+            data: data.as_bytes(),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    /// Skips whitespace and at most one comma between arguments.
+    fn skip_separator(&mut self) {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.position += 1;
+            self.skip_whitespace();
+        }
+    }
+
+    fn at_end(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn at_number(&self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'-' | b'+'))
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        match self.peek() {
+            Some(c) if c.is_ascii_graphic() => ParseError::new(
+                self.position,
+                format!("expected {}, found '{}'", expected, c as char),
+            ),
+            Some(_) => ParseError::new(self.position, format!("expected {}", expected)),
+            None => ParseError::new(
+                self.position,
+                format!("expected {}, found end of data", expected),
+            ),
+        }
+    }
+
+    fn number(&mut self) -> Result<f32, ParseError> {
+        let start = self.position;
+        let digits = |tokens: &mut Tokens| {
+            let start = tokens.position;
+            while let Some(b'0'..=b'9') = tokens.peek() {
+                tokens.position += 1;
+            }
+            tokens.position > start
+        };
+
+        if let Some(b'-' | b'+') = self.peek() {
+            self.position += 1;
+        }
+        let mut valid = digits(self);
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            valid |= digits(self);
+        }
+        if !valid {
+            self.position = start;
+            return Err(self.error("number"));
+        }
+
+        // Only consume an exponent if digits follow it.
+        if let Some(b'e' | b'E') = self.peek() {
+            let mantissa_end = self.position;
+            self.position += 1;
+            if let Some(b'-' | b'+') = self.peek() {
+                self.position += 1;
+            }
+            if !digits(self) {
+                self.position = mantissa_end;
+            }
+        }
+
+        let text = std::str::from_utf8(&self.data[start..self.position]).unwrap();
+        let value = text
+            .parse()
+            .map_err(|_| ParseError::new(start, "invalid number"))?;
+        self.skip_separator();
+        Ok(value)
+    }
+
+    fn point(&mut self) -> Result<Vec2, ParseError> {
+        let x = self.number()?;
+        let y = self.number()?;
+        Ok(Vec2::new(x, y))
+    }
+
+    /// Reads an arc flag, which need not be separated from what follows.
+    fn flag(&mut self) -> Result<bool, ParseError> {
+        let value = match self.peek() {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(self.error("flag")),
+        };
+        self.position += 1;
+        self.skip_separator();
+        Ok(value)
+    }
+}
+
+/// Parses SVG path data (the `d` attribute) into `builder`, converting
+/// arcs and cubics to quadratics. Subpaths without `Z` are left open, so
+/// they can be stroked; building the path closes them for filling.
+pub fn parse_path_data(data: &str, builder: &mut PathBuilder) -> Result<(), ParseError> {
+    let mut tokens = Tokens::new(data);
+
+    let mut command: Option<u8> = None;
+    let mut current = Vec2::new(0.0, 0.0);
+    let mut start = current;
+    // Whether the next drawing command must first begin a new subpath.
+    let mut needs_move = true;
+    let mut open = false;
+    // Control points of the previous segment, reflected by `S` and `T`.
+    let mut last_cubic: Option<Vec2> = None;
+    let mut last_quadratic: Option<Vec2> = None;
+
+    tokens.skip_whitespace();
+    while let Some(c) = tokens.peek() {
+        let position = tokens.position;
+        let name = if c.is_ascii_alphabetic() {
+            tokens.position += 1;
+            tokens.skip_whitespace();
+            c
+        } else if tokens.at_number() {
+            // Repeated arguments continue the previous command, with a move
+            // becoming a line.
+            match command {
+                Some(b'M') => b'L',
+                Some(b'm') => b'l',
+                Some(b'Z' | b'z') | None => return Err(tokens.error("command")),
+                Some(name) => name,
+            }
+        } else {
+            return Err(tokens.error("command"));
+        };
+
+        if command.is_none() && !matches!(name, b'M' | b'm') {
+            return Err(ParseError::new(
+                position,
+                "path data must begin with a move command",
+            ));
+        }
+
+        let relative = name.is_ascii_lowercase();
+        let origin = if relative {
+            current
+        } else {
+            Vec2::new(0.0, 0.0)
+        };
+
+        if !matches!(name.to_ascii_uppercase(), b'M' | b'Z') && needs_move {
+            builder.move_to(current);
+            needs_move = false;
+            open = true;
+        }
+
+        let mut cubic = None;
+        let mut quadratic = None;
+        match name.to_ascii_uppercase() {
+            b'M' => {
+                current = origin + tokens.point()?;
+                start = current;
+                builder.move_to(current);
+                needs_move = false;
+                open = true;
+            }
+            b'L' => {
+                current = origin + tokens.point()?;
+                builder.line_to(current);
+            }
+            b'H' => {
+                current.x = origin.x + tokens.number()?;
+                builder.line_to(current);
+            }
+            b'V' => {
+                current.y = origin.y + tokens.number()?;
+                builder.line_to(current);
+            }
+            b'C' | b'S' => {
+                let control1 = if name.eq_ignore_ascii_case(&b'C') {
+                    origin + tokens.point()?
+                } else {
+                    last_cubic.map_or(current, |control| 2.0 * current - control)
+                };
+                let control2 = origin + tokens.point()?;
+                current = origin + tokens.point()?;
+                builder.cubic_to(control1, control2, current);
+                cubic = Some(control2);
+            }
+            b'Q' | b'T' => {
+                let control = if name.eq_ignore_ascii_case(&b'Q') {
+                    origin + tokens.point()?
+                } else {
+                    last_quadratic.map_or(current, |control| 2.0 * current - control)
+                };
+                current = origin + tokens.point()?;
+                builder.quadratic_to(control, current);
+                quadratic = Some(control);
+            }
+            b'A' => {
+                let radii = Vec2::new(tokens.number()?, tokens.number()?);
+                let rotation = tokens.number()?.to_radians();
+                let large_arc = tokens.flag()?;
+                let sweep = tokens.flag()?;
+                current = origin + tokens.point()?;
+                builder.arc_to(radii, rotation, large_arc, sweep, current);
+            }
+            b'Z' => {
+                if open {
+                    builder.close();
+                    open = false;
+                }
+                current = start;
+                needs_move = true;
+            }
+            _ => {
+                return Err(ParseError::new(
+                    position,
+                    format!("unknown command '{}'", name as char),
+                ))
+            }
+        }
+
+        command = Some(name);
+        last_cubic = cubic;
+        last_quadratic = quadratic;
+    }
+
+    Ok(())
+}
+
+/// A filled shape from an SVG document.
+pub struct Shape {
+    pub path: Path,
+    /// sRGB-encoded color with unpremultiplied alpha.
+    pub color: [f32; 4],
+}
+
+/// The shapes of an SVG document, in drawing order.
+pub struct Document {
+    pub shapes: Vec<Shape>,
+    /// Width and height of the viewport.
+    pub size: Vec2,
+}
+
+/// Inherited presentation attributes.
+#[derive(Clone)]
+struct Style {
+    transform: Mat2x3,
+    /// `None` for `fill="none"`.
+    fill: Option<[f32; 3]>,
+    fill_opacity: f32,
+    opacity: f32,
+    fill_rule: FillRule,
+    /// `None` for `stroke="none"`.
+    stroke: Option<[f32; 3]>,
+    stroke_opacity: f32,
+    stroke_style: StrokeStyle,
+    /// Empty for `stroke-dasharray="none"`.
+    dashes: Vec<f32>,
+    dash_offset: f32,
+}
+
+/// An attribute with the byte offset of its value in the source.
+struct Attribute<'a> {
+    name: &'a str,
+    value: &'a str,
+    position: usize,
+}
+
+fn offset(error: ParseError, position: usize) -> ParseError {
+    ParseError::new(error.position + position, error.message)
+}
+
+/// Parses a list of numbers separated by whitespace and/or commas.
+fn numbers(value: &str, position: usize) -> Result<Vec<f32>, ParseError> {
+    let mut tokens = Tokens::new(value);
+    let mut numbers = Vec::new();
+    tokens.skip_whitespace();
+    while !tokens.at_end() {
+        numbers.push(tokens.number().map_err(|error| offset(error, position))?);
+    }
+    Ok(numbers)
+}
+
+/// Parses a length in user units, optionally suffixed with `px`.
+fn length(attribute: &Attribute) -> Result<f32, ParseError> {
+    let mut tokens = Tokens::new(attribute.value);
+    tokens.skip_whitespace();
+    let value = tokens
+        .number()
+        .map_err(|error| offset(error, attribute.position))?;
+    if tokens.data[tokens.position..].starts_with(b"px") {
+        tokens.position += 2;
+        tokens.skip_whitespace();
+    }
+    if !tokens.at_end() {
+        return Err(offset(tokens.error("end of length"), attribute.position));
+    }
+    Ok(value)
+}
+
+fn parse_transform(value: &str, position: usize) -> Result<Mat2x3, ParseError> {
+    let mut transform = Mat2x3::id();
+    let mut rest = value;
+    loop {
+        let trimmed = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+        if trimmed.is_empty() {
+            return Ok(transform);
+        }
+        let start = position + (value.len() - trimmed.len());
+
+        let open = trimmed
+            .find('(')
+            .ok_or_else(|| ParseError::new(start, "expected '(' in transform"))?;
+        let close = trimmed[open + 1..]
+            .find(')')
+            .map(|i| open + 1 + i)
+            .ok_or_else(|| ParseError::new(start + open, "expected ')' in transform"))?;
+        let name = trimmed[..open].trim();
+        let args = numbers(&trimmed[open + 1..close], start + open + 1)?;
+
+        let arity_error =
+            || ParseError::new(start, format!("wrong number of arguments to {}", name));
+        let next = match (name, args.as_slice()) {
+            // SVG lists the matrix by columns.
+            ("matrix", &[a, b, c, d, e, f]) => Mat2x3([a, c, e, b, d, f]),
+            ("translate", &[x]) => Mat2x3::translate(x, 0.0),
+            ("translate", &[x, y]) => Mat2x3::translate(x, y),
+            ("scale", &[x]) => Mat2x3::scale(x, x),
+            ("scale", &[x, y]) => Mat2x3::scale(x, y),
+            ("rotate", &[angle]) | ("rotate", &[angle, _, _]) => {
+                let rotate = Mat2x3::rotate(angle.to_radians());
+                match args.as_slice() {
+                    &[_, x, y] => Mat2x3::translate(x, y) * rotate * Mat2x3::translate(-x, -y),
+                    _ => rotate,
+                }
+            }
+            ("skewX", &[angle]) => Mat2x3::skew(angle.to_radians().tan(), 0.0),
+            ("skewY", &[angle]) => Mat2x3::skew(0.0, angle.to_radians().tan()),
+            ("matrix" | "translate" | "scale" | "rotate" | "skewX" | "skewY", _) => {
+                return Err(arity_error())
+            }
+            _ => {
+                return Err(ParseError::new(
+                    start,
+                    format!("unknown transform '{}'", name),
+                ))
+            }
+        };
+        transform = transform * next;
+        rest = &trimmed[close + 1..];
+    }
+}
+
+/// Parses `#rgb`, `#rrggbb`, `rgb(r, g, b)` or a basic color keyword.
+/// Returns `None` for `none`.
+fn parse_color(value: &str, position: usize) -> Result<Option<[f32; 3]>, ParseError> {
+    let value = value.trim();
+    let hex = |digits: &str| u8::from_str_radix(digits, 16).ok();
+    let color = if let Some(digits) = value.strip_prefix('#') {
+        // Checking the digits first also keeps the slices below on character
+        // boundaries.
+        match digits.len() {
+            _ if !digits.bytes().all(|b| b.is_ascii_hexdigit()) => None,
+            3 => (0..3)
+                .map(|i| hex(&digits[i..i + 1]).map(|v| v as f32 * 17.0 / 255.0))
+                .collect::<Option<Vec<f32>>>(),
+            6 => (0..3)
+                .map(|i| hex(&digits[2 * i..2 * i + 2]).map(|v| v as f32 / 255.0))
+                .collect::<Option<Vec<f32>>>(),
+            _ => None,
+        }
+    } else if let Some(args) = value.strip_prefix("rgb(").and_then(|v| v.strip_suffix(')')) {
+        let args = numbers(args, position + 4)?;
+        (args.len() == 3).then(|| args.iter().map(|v| v.clamp(0.0, 255.0) / 255.0).collect())
+    } else {
+        let rgb = match value {
+            "none" | "transparent" => return Ok(None),
+            "black" | "currentColor" => [0, 0, 0],
+            "white" => [255, 255, 255],
+            "gray" | "grey" => [128, 128, 128],
+            "red" => [255, 0, 0],
+            "green" => [0, 128, 0],
+            "lime" => [0, 255, 0],
+            "blue" => [0, 0, 255],
+            "yellow" => [255, 255, 0],
+            "cyan" | "aqua" => [0, 255, 255],
+            "magenta" | "fuchsia" => [255, 0, 255],
+            "orange" => [255, 165, 0],
+            "purple" => [128, 0, 128],
+            _ => {
+                return Err(ParseError::new(
+                    position,
+                    format!("unknown color '{}'", value),
+                ))
+            }
+        };
+        Some(rgb.iter().map(|&v| v as f32 / 255.0).collect())
+    };
+
+    match color {
+        Some(color) => Ok(Some([color[0], color[1], color[2]])),
+        None => Err(ParseError::new(
+            position,
+            format!("invalid color '{}'", value),
+        )),
+    }
+}
+
+fn parse_number(attribute: &Attribute) -> Result<f32, ParseError> {
+    let mut tokens = Tokens::new(attribute.value);
+    tokens.skip_whitespace();
+    let value = tokens
+        .number()
+        .map_err(|error| offset(error, attribute.position))?;
+    if !tokens.at_end() {
+        return Err(offset(tokens.error("end of number"), attribute.position));
+    }
+    Ok(value)
+}
+
+fn parse_opacity(attribute: &Attribute) -> Result<f32, ParseError> {
+    Ok(parse_number(attribute)?.clamp(0.0, 1.0))
+}
+
+impl Style {
+    /// Applies a presentation attribute, or a property from a `style`
+    /// attribute, that affects filling.
+    fn apply(&mut self, attribute: &Attribute) -> Result<(), ParseError> {
+        match attribute.name {
+            "fill" => self.fill = parse_color(attribute.value, attribute.position)?,
+            "fill-opacity" => self.fill_opacity = parse_opacity(attribute)?,
+            // Group opacity is approximated by fading each shape, which
+            // differs where shapes in the group overlap.
+            "opacity" => self.opacity *= parse_opacity(attribute)?,
+            "fill-rule" => {
+                self.fill_rule = match attribute.value.trim() {
+                    "nonzero" => FillRule::NonZero,
+                    "evenodd" => FillRule::EvenOdd,
+                    _ => return Err(ParseError::new(attribute.position, "invalid fill rule")),
+                }
+            }
+            "stroke" => self.stroke = parse_color(attribute.value, attribute.position)?,
+            "stroke-opacity" => self.stroke_opacity = parse_opacity(attribute)?,
+            "stroke-width" => self.stroke_style.width = length(attribute)?,
+            "stroke-miterlimit" => self.stroke_style.miter_limit = parse_number(attribute)?,
+            "stroke-dasharray" => {
+                self.dashes = match attribute.value.trim() {
+                    "none" => Vec::new(),
+                    _ => {
+                        let dashes = numbers(attribute.value, attribute.position)?;
+                        if dashes.iter().any(|&dash| dash < 0.0) {
+                            return Err(ParseError::new(
+                                attribute.position,
+                                "negative dash length",
+                            ));
+                        }
+                        dashes
+                    }
+                }
+            }
+            "stroke-dashoffset" => self.dash_offset = length(attribute)?,
+            "stroke-linejoin" => {
+                self.stroke_style.join = match attribute.value.trim() {
+                    "miter" => LineJoin::Miter,
+                    "round" => LineJoin::Round,
+                    "bevel" => LineJoin::Bevel,
+                    _ => return Err(ParseError::new(attribute.position, "invalid line join")),
+                }
+            }
+            "stroke-linecap" => {
+                self.stroke_style.cap = match attribute.value.trim() {
+                    "butt" => LineCap::Butt,
+                    "round" => LineCap::Round,
+                    "square" => LineCap::Square,
+                    _ => return Err(ParseError::new(attribute.position, "invalid line cap")),
+                }
+            }
+            "transform" => {
+                self.transform =
+                    self.transform * parse_transform(attribute.value, attribute.position)?
+            }
+            "style" => {
+                let mut position = attribute.position;
+                for declaration in attribute.value.split(';') {
+                    if let Some((name, value)) = declaration.split_once(':') {
+                        let value_position = position + name.len() + 1;
+                        let trimmed = value.trim_start();
+                        self.apply(&Attribute {
+                            name: name.trim(),
+                            value: trimmed,
+                            position: value_position + (value.len() - trimmed.len()),
+                        })?;
+                    }
+                    position += declaration.len() + 1;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Reads the elements of an XML document.
+struct Xml<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+/// A start or end tag.
+enum Tag<'a> {
+    Start {
+        /// Offset of the element name.
+        position: usize,
+        name: &'a str,
+        attributes: Vec<Attribute<'a>>,
+        empty: bool,
+    },
+    End,
+}
+
+impl<'a> Xml<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn skip_until(&mut self, pattern: &str, what: &str) -> Result<(), ParseError> {
+        match self.rest().find(pattern) {
+            Some(index) => {
+                self.position += index + pattern.len();
+                Ok(())
+            }
+            None => Err(ParseError::new(
+                self.position,
+                format!("unterminated {}", what),
+            )),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn name(&mut self) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | ':' | '.')))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(ParseError::new(self.position, "expected name"));
+        }
+        self.position += len;
+        Ok(&rest[..len])
+    }
+
+    /// Returns the next tag, skipping text, comments, declarations and
+    /// processing instructions, or `None` at the end of the document.
+    fn next(&mut self) -> Result<Option<Tag<'a>>, ParseError> {
+        loop {
+            match self.rest().find('<') {
+                Some(index) => self.position += index,
+                None => return Ok(None),
+            }
+
+            let rest = self.rest();
+            if rest.starts_with("<!--") {
+                self.skip_until("-->", "comment")?;
+            } else if rest.starts_with("<![CDATA[") {
+                self.skip_until("]]>", "CDATA section")?;
+            } else if rest.starts_with("<?") {
+                self.skip_until("?>", "processing instruction")?;
+            } else if rest.starts_with("<!") {
+                self.skip_until(">", "declaration")?;
+            } else if rest.starts_with("</") {
+                self.skip_until(">", "end tag")?;
+                return Ok(Some(Tag::End));
+            } else {
+                self.position += 1;
+                return self.start_tag().map(Some);
+            }
+        }
+    }
+
+    fn start_tag(&mut self) -> Result<Tag<'a>, ParseError> {
+        let position = self.position;
+        let name = self.name()?;
+        let mut attributes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.position += 2;
+                return Ok(Tag::Start {
+                    position,
+                    name,
+                    attributes,
+                    empty: true,
+                });
+            } else if rest.starts_with('>') {
+                self.position += 1;
+                return Ok(Tag::Start {
+                    position,
+                    name,
+                    attributes,
+                    empty: false,
+                });
+            } else if rest.is_empty() {
+                return Err(ParseError::new(self.position, "unterminated start tag"));
+            }
+
+            let attribute_name = self.name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(ParseError::new(self.position, "expected '='"));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(ParseError::new(self.position, "expected quoted value")),
+            };
+            let position = self.position + 1;
+            let len = self.source[position..]
+                .find(quote)
+                .ok_or_else(|| ParseError::new(self.position, "unterminated attribute value"))?;
+            attributes.push(Attribute {
+                name: attribute_name,
+                value: &self.source[position..position + len],
+                position,
+            });
+            self.position = position + len + 1;
+        }
+    }
+}
+
+/// Builds the outline of a basic shape element, or returns `None` for
+/// elements that aren't shapes.
+fn shape_outline(
+    name: &str,
+    attributes: &[Attribute],
+    builder: &mut PathBuilder,
+) -> Result<Option<()>, ParseError> {
+    let get = |key: &str| -> Result<f32, ParseError> {
+        attributes
+            .iter()
+            .find(|attribute| attribute.name == key)
+            .map_or(Ok(0.0), length)
+    };
+
+    match name {
+        "path" => {
+            if let Some(d) = attributes.iter().find(|attribute| attribute.name == "d") {
+                parse_path_data(d.value, builder).map_err(|error| offset(error, d.position))?;
+            }
+        }
+        "rect" => {
+            let (x, y, width, height) = (get("x")?, get("y")?, get("width")?, get("height")?);
+            let (mut rx, mut ry) = (get("rx")?, get("ry")?);
+            let has = |key: &str| attributes.iter().any(|attribute| attribute.name == key);
+            if !has("rx") {
+                rx = ry;
+            }
+            if !has("ry") {
+                ry = rx;
+            }
+            let (rx, ry) = (rx.min(0.5 * width), ry.min(0.5 * height));
+            if width <= 0.0 || height <= 0.0 {
+                return Ok(Some(()));
+            }
+
+            let (min, max) = (Vec2::new(x, y), Vec2::new(x + width, y + height));
+            if rx > 0.0 && ry > 0.0 {
+                builder.rounded_rect(min, max, [Vec2::new(rx, ry); 4]);
+            } else {
+                builder.rect(min, max);
+            }
+        }
+        "circle" => {
+            let (center, radius) = (Vec2::new(get("cx")?, get("cy")?), get("r")?);
+            if radius > 0.0 {
+                builder.circle(center, radius);
+            }
+        }
+        "ellipse" => {
+            let center = Vec2::new(get("cx")?, get("cy")?);
+            let radii = Vec2::new(get("rx")?, get("ry")?);
+            if radii.x > 0.0 && radii.y > 0.0 {
+                builder.ellipse(center, radii);
+            }
+        }
+        "polygon" | "polyline" => {
+            if let Some(points) = attributes
+                .iter()
+                .find(|attribute| attribute.name == "points")
+            {
+                let values = numbers(points.value, points.position)?;
+                for (i, point) in values.chunks_exact(2).enumerate() {
+                    let point = Vec2::new(point[0], point[1]);
+                    if i == 0 {
+                        builder.move_to(point);
+                    } else {
+                        builder.line_to(point);
+                    }
+                }
+                if name == "polygon" {
+                    builder.close();
+                }
+            }
+        }
+        _ => return Ok(None),
+    }
+
+    Ok(Some(()))
+}
+
+/// Parses an SVG document into filled shapes. Supports `path`, `rect`,
+/// `circle`, `ellipse`, `polygon` and `polyline` elements within nested
+/// `g` elements, with `transform`, opacity, and solid fills and dashed or
+/// solid strokes. Gradients and other elements are ignored.
+///
+/// Shapes are returned with y pointing up and the origin at the top left
+/// of the viewport, like text layout. Curves are approximated within
+/// `tolerance` in those units.
+pub fn parse_document(source: &str, tolerance: f32) -> Result<Document, ParseError> {
+    let mut xml = Xml {
+        source,
+        position: 0,
+    };
+
+    let root = Style {
+        transform: Mat2x3::scale(1.0, -1.0),
+        fill: Some([0.0, 0.0, 0.0]),
+        fill_opacity: 1.0,
+        opacity: 1.0,
+        fill_rule: FillRule::NonZero,
+        stroke: None,
+        stroke_opacity: 1.0,
+        stroke_style: StrokeStyle::new(1.0),
+        dashes: Vec::new(),
+        dash_offset: 0.0,
+    };
+
+    let mut shapes = Vec::new();
+    let mut size = Vec2::new(0.0, 0.0);
+    let mut found_root = false;
+    // Styles of the open elements, with `None` for elements whose content
+    // is skipped.
+    let mut stack: Vec<Option<Style>> = Vec::new();
+
+    while let Some(tag) = xml.next()? {
+        let (position, name, attributes, empty) = match tag {
+            Tag::Start {
+                position,
+                name,
+                attributes,
+                empty,
+            } => (position, name, attributes, empty),
+            Tag::End => {
+                stack.pop();
+                continue;
+            }
+        };
+
+        let parent = match stack.last() {
+            Some(Some(parent)) => parent.clone(),
+            Some(None) => {
+                if !empty {
+                    stack.push(None);
+                }
+                continue;
+            }
+            None if name == "svg" && !found_root => {
+                found_root = true;
+                let mut style = root.clone();
+                let attribute = |key: &str| attributes.iter().find(|a| a.name == key);
+
+                let view_box = match attribute("viewBox") {
+                    Some(view_box) => {
+                        let values = numbers(view_box.value, view_box.position)?;
+                        if values.len() != 4 {
+                            return Err(ParseError::new(
+                                view_box.position,
+                                "viewBox must have four numbers",
+                            ));
+                        }
+                        Some(values)
+                    }
+                    None => None,
+                };
+                let width = attribute("width").map(length).transpose()?;
+                let height = attribute("height").map(length).transpose()?;
+                size = match (&view_box, width, height) {
+                    (_, Some(width), Some(height)) => Vec2::new(width, height),
+                    (Some(view_box), _, _) => Vec2::new(view_box[2], view_box[3]),
+                    _ => Vec2::new(0.0, 0.0),
+                };
+                if let Some(view_box) = view_box {
+                    if view_box[2] > 0.0 && view_box[3] > 0.0 {
+                        style.transform = style.transform
+                            * Mat2x3::scale(size.x / view_box[2], size.y / view_box[3])
+                            * Mat2x3::translate(-view_box[0], -view_box[1]);
+                    }
+                }
+                for attribute in attributes.iter().filter(|a| a.name != "transform") {
+                    style.apply(attribute)?;
+                }
+
+                if !empty {
+                    stack.push(Some(style));
+                }
+                continue;
+            }
+            None => {
+                return Err(ParseError::new(
+                    position,
+                    format!("expected svg element, found '{}'", name),
+                ))
+            }
+        };
+
+        let mut style = parent;
+        for attribute in attributes.iter() {
+            style.apply(attribute)?;
+        }
+
+        let mut builder = PathBuilder::new();
+        builder.tolerance(Tolerance::Absolute(
+            tolerance / style.transform.max_scale().max(1e-6),
+        ));
+        builder.fill_rule(style.fill_rule);
+        let is_shape = shape_outline(name, &attributes, &mut builder)?.is_some();
+
+        if is_shape {
+            let transform = style.transform;
+            let mut push = |mut builder: PathBuilder, color: [f32; 3], opacity: f32| {
+                builder.transform(&transform);
+                let path = builder.build();
+                if path.points.len() > 2 {
+                    shapes.push(Shape {
+                        path,
+                        color: [color[0], color[1], color[2], opacity * style.opacity],
+                    });
+                }
+            };
+
+            // Strokes are drawn over fills, and are built before transforming
+            // so that their width scales with the shape.
+            let stroke = style.stroke.map(|color| {
+                let stroke = if style.dashes.is_empty() {
+                    builder.stroke(&style.stroke_style)
+                } else {
+                    builder
+                        .dash(&style.dashes, style.dash_offset)
+                        .stroke(&style.stroke_style)
+                };
+                (stroke, color)
+            });
+            if let Some(fill) = style.fill {
+                push(builder, fill, style.fill_opacity);
+            }
+            if let Some((stroke, color)) = stroke {
+                push(stroke, color, style.stroke_opacity);
+            }
+        }
+
+        if !empty {
+            // Only groups have rendered content.
+            stack.push((name == "g").then_some(style));
+        }
+    }
+
+    if !found_root {
+        return Err(ParseError::new(0, "missing svg element"));
+    }
+
+    Ok(Document { shapes, size })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &str) -> Result<Vec<PathSegment>, ParseError> {
+        let mut builder = PathBuilder::new();
+        parse_path_data(data, &mut builder)?;
+        Ok(builder.iter().collect())
+    }
+
+    fn error_position(data: &str) -> usize {
+        parse(data).unwrap_err().position
+    }
+
+    fn document_error(source: &str) -> ParseError {
+        match parse_document(source, 0.1) {
+            Ok(_) => panic!("expected an error"),
+            Err(error) => error,
+        }
+    }
+
+    fn point(x: f32, y: f32) -> Vec2 {
+        Vec2::new(x, y)
+    }
+
+    #[test]
+    fn implicit_commands() {
+        assert_eq!(
+            parse("M10 20 30 40 50,60").unwrap(),
+            vec![
+                PathSegment::MoveTo(point(10.0, 20.0)),
+                PathSegment::Line(point(30.0, 40.0)),
+                PathSegment::Line(point(50.0, 60.0)),
+            ]
+        );
+        assert_eq!(
+            parse("m1 1 2 2h3v-1").unwrap(),
+            vec![
+                PathSegment::MoveTo(point(1.0, 1.0)),
+                PathSegment::Line(point(3.0, 3.0)),
+                PathSegment::Line(point(6.0, 3.0)),
+                PathSegment::Line(point(6.0, 2.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn close_and_quadratics() {
+        assert_eq!(
+            parse("M0 0Q5 10 10 0T20 0zL1 1").unwrap(),
+            vec![
+                PathSegment::MoveTo(point(0.0, 0.0)),
+                PathSegment::Quad(point(5.0, 10.0), point(10.0, 0.0)),
+                PathSegment::Quad(point(15.0, -10.0), point(20.0, 0.0)),
+                PathSegment::Line(point(0.0, 0.0)),
+                PathSegment::Close,
+                PathSegment::MoveTo(point(0.0, 0.0)),
+                PathSegment::Line(point(1.0, 1.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn exponents_and_compact_numbers() {
+        assert_eq!(
+            parse("M1e2-1E-1.5.5").unwrap(),
+            vec![
+                PathSegment::MoveTo(point(100.0, -0.1)),
+                PathSegment::Line(point(0.5, 0.5)),
+            ]
+        );
+        // An `e` without digits ends the number instead of starting an
+        // exponent.
+        assert_eq!(error_position("M0 0 L1e"), 7);
+        assert_eq!(error_position("M0 0 L1e+ 2"), 7);
+    }
+
+    fn distance_to_polyline(point: Vec2, polyline: &[Vec2]) -> f32 {
+        polyline
+            .windows(2)
+            .map(|pair| {
+                let ab = pair[1] - pair[0];
+                let t = ((point - pair[0]).dot(ab) / ab.dot(ab).max(1e-12)).clamp(0.0, 1.0);
+                (pair[0] + t * ab - point).length()
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    #[test]
+    fn cubics_within_tolerance() {
+        const SAMPLES: usize = 256;
+        let tolerance = 0.05;
+        let cases = [
+            (
+                "M0 0C800 0 -200 600 600 600",
+                vec![[(0.0, 0.0), (800.0, 0.0), (-200.0, 600.0), (600.0, 600.0)]],
+            ),
+            // The smooth curve reflects the previous second control point.
+            (
+                "M0 0c0 100 100 100 100 0s100-100 100 0",
+                vec![
+                    [(0.0, 0.0), (0.0, 100.0), (100.0, 100.0), (100.0, 0.0)],
+                    [(100.0, 0.0), (100.0, -100.0), (200.0, -100.0), (200.0, 0.0)],
+                ],
+            ),
+        ];
+
+        for (data, cubics) in cases {
+            let mut builder = PathBuilder::new();
+            builder.tolerance(Tolerance::Absolute(tolerance));
+            parse_path_data(data, &mut builder).unwrap();
+
+            let mut approximation = Vec::new();
+            let mut last = point(0.0, 0.0);
+            for segment in builder.iter() {
+                let (control, end) = match segment {
+                    PathSegment::MoveTo(point) => (point, point),
+                    PathSegment::Line(point) => (Vec2::lerp(0.5, last, point), point),
+                    PathSegment::Quad(control, point) => (control, point),
+                    PathSegment::Close => continue,
+                };
+                for i in 0..=SAMPLES {
+                    let t = i as f32 / SAMPLES as f32;
+                    approximation.push(Vec2::lerp(
+                        t,
+                        Vec2::lerp(t, last, control),
+                        Vec2::lerp(t, control, end),
+                    ));
+                }
+                last = end;
+            }
+
+            let mut curve = Vec::new();
+            for cubic in cubics {
+                let [p1, p2, p3, p4] = cubic.map(|(x, y)| point(x, y));
+                for i in 0..=SAMPLES {
+                    let t = i as f32 / SAMPLES as f32;
+                    let q1 = Vec2::lerp(t, Vec2::lerp(t, p1, p2), Vec2::lerp(t, p2, p3));
+                    let q2 = Vec2::lerp(t, Vec2::lerp(t, p2, p3), Vec2::lerp(t, p3, p4));
+                    curve.push(Vec2::lerp(t, q1, q2));
+                }
+            }
+
+            let deviation = approximation
+                .iter()
+                .map(|&point| distance_to_polyline(point, &curve))
+                .chain(
+                    curve
+                        .iter()
+                        .map(|&point| distance_to_polyline(point, &approximation)),
+                )
+                .fold(0.0, f32::max);
+            assert!(deviation <= tolerance, "{}: deviation {}", data, deviation);
+        }
+    }
+
+    #[test]
+    fn arc_flags() {
+        let end = |data: &str| match parse(data).unwrap().last() {
+            Some(&PathSegment::Quad(_, end)) => end,
+            segment => panic!("unexpected {:?}", segment),
+        };
+        let spaced = end("M0 0 a5 5 0 1 1 10 0");
+        let compact = end("M0 0a5 5 0 1110 0");
+        assert!((spaced - point(10.0, 0.0)).length() < 1e-4);
+        assert!((compact - point(10.0, 0.0)).length() < 1e-4);
+
+        assert_eq!(error_position("M0 0 A5 5 0 2 1 10 0"), 12);
+        assert_eq!(error_position("M0 0 A5 5 0 1 -1 10 0"), 14);
+    }
+
+    #[test]
+    fn command_errors() {
+        assert_eq!(error_position("10 20"), 0);
+        assert_eq!(error_position("  L1 1"), 2);
+        assert_eq!(error_position("M0 0 Z 5 5"), 7);
+        assert_eq!(error_position("M0 0 X1 1"), 5);
+        assert_eq!(error_position("M0 0 L1"), 7);
+        assert_eq!(error_position("M0 0 L1 #"), 8);
+
+        let error = parse("M0 0 L1").unwrap_err();
+        assert_eq!(error.message, "expected number, found end of data");
+    }
+
+    #[test]
+    fn transform_matrix_columns() {
+        // `matrix(a, b, c, d, e, f)` maps (x, y) to
+        // (a * x + c * y + e, b * x + d * y + f).
+        let m = parse_transform("matrix(1, 2, 3, 4, 5, 6)", 0).unwrap();
+        let p = m * point(1.0, 10.0);
+        assert!((p - point(36.0, 48.0)).length() < 1e-4);
+
+        let m = parse_transform("translate(10) rotate(90) scale(2, 1)", 0).unwrap();
+        let p = m * point(1.0, 0.0);
+        assert!((p - point(10.0, 2.0)).length() < 1e-4);
+    }
+
+    #[test]
+    fn malformed_transforms_and_colors() {
+        // A ')' before the '(' is not the closing one, so the '(' is
+        // reported as unclosed.
+        match parse_transform("scale)(2", 10) {
+            Ok(_) => panic!("expected an error"),
+            Err(error) => assert_eq!(error.position, 16),
+        }
+        assert!(parse_transform("scale(2) rotate)(1", 0).is_err());
+
+        // Multibyte characters are rejected rather than sliced through.
+        assert!(parse_color("#é1", 0).is_err());
+        assert!(parse_color("#ééé", 0).is_err());
+        assert!(parse_color("#+f0", 0).is_err());
+        assert_eq!(parse_color("#fA0", 0).unwrap(), Some([1.0, 2.0 / 3.0, 0.0]));
+    }
+
+    #[test]
+    fn document_error_positions() {
+        let source = r##"<svg><path d="M0 0 L1e"/></svg>"##;
+        let d = source.find("M0").unwrap();
+        assert_eq!(document_error(source).position, d + 7);
+
+        let source = r##"<svg><path fill="#zzz" d="M0 0"/></svg>"##;
+        assert_eq!(
+            document_error(source).position,
+            source.find("#zzz").unwrap()
+        );
+
+        let source = r##"<svg><rect style="fill: red;stroke-width:  2q" width="1"/></svg>"##;
+        assert_eq!(
+            document_error(source).position,
+            source.find("2q").unwrap() + 1
+        );
+
+        let source = r##"<svg><g style="fill:#0f0 ; fill-rule: odd"/></svg>"##;
+        assert_eq!(document_error(source).position, source.find("odd").unwrap());
+
+        let source = r##"<svg><path d="M0 0" transform="translate(1) skewX(1, 2)"/></svg>"##;
+        assert_eq!(
+            document_error(source).position,
+            source.find("skewX").unwrap()
+        );
+
+        let source = r##"<?xml version="1.0"?><html/>"##;
+        assert_eq!(
+            document_error(source).position,
+            source.find("html").unwrap()
+        );
+    }
+}
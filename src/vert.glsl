@@ -7,14 +7,30 @@ layout(location = 0) in vec3 aPos;
 layout(location = 1) in vec2 aUv;
 layout(location = 2) in uvec2 aComponentsRange;
 layout(location = 3) in uvec2 aPointsRange;
+layout(location = 4) in uint aFillRule;
+layout(location = 5) in vec2 aExtent;
+layout(location = 6) in vec2 aUvScale;
+layout(location = 7) in vec4 aColor;
 
 layout(location = 0) out vec2 vUv;
 layout(location = 1) flat out uvec2 vComponentsRange;
 layout(location = 2) flat out uvec2 vPointsRange;
+layout(location = 3) flat out uint vFillRule;
+layout(location = 4) flat out vec2 vExtent;
+layout(location = 5) flat out vec4 vColor;
+
+// Defined in `dilate.glsl`, which is appended to this source at load.
+vec2 dilate(vec3 pos, vec2 outward);
 
 void main() {
-    vUv = aUv;
+    vec2 outward = sign(aUv - 0.5) * vec2(notEqual(aUvScale, vec2(0.0)));
+    vec2 offset = dilate(aPos, outward);
+
+    vUv = aUv + offset * aUvScale;
     vComponentsRange = aComponentsRange;
     vPointsRange = aPointsRange;
-    gl_Position = uTransform * vec4(aPos, 1.f);
+    vFillRule = aFillRule;
+    vExtent = aExtent;
+    vColor = aColor;
+    gl_Position = uTransform * vec4(aPos.xy + offset, aPos.z, 1.f);
 }
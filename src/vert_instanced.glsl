@@ -0,0 +1,47 @@
+#version 410
+
+uniform vec2 uScreenSize;
+uniform mat4 uTransform;
+
+layout(location = 0) in vec3 aOrigin;
+layout(location = 1) in float aScale;
+layout(location = 2) in vec4 aBbox;
+layout(location = 3) in uvec2 aComponentsRange;
+layout(location = 4) in uvec2 aPointsRange;
+layout(location = 5) in uint aFillRule;
+layout(location = 6) in vec2 aExtent;
+layout(location = 7) in vec4 aColor;
+
+layout(location = 0) out vec2 vUv;
+layout(location = 1) flat out uvec2 vComponentsRange;
+layout(location = 2) flat out uvec2 vPointsRange;
+layout(location = 3) flat out uint vFillRule;
+layout(location = 4) flat out vec2 vExtent;
+layout(location = 5) flat out vec4 vColor;
+
+// Defined in `dilate.glsl`, which is appended to this source at load.
+vec2 dilate(vec3 pos, vec2 outward);
+
+void main() {
+    // Quad corners in the order (0, 0), (1, 0), (1, 1), (0, 1).
+    vec2 corner = vec2(float(gl_VertexID == 1 || gl_VertexID == 2), float(gl_VertexID >= 2));
+    vec2 size = aBbox.zw - aBbox.xy;
+
+    // Empty glyphs have no extent to extrapolate the UVs over.
+    bool empty = size.x <= 0.0 || size.y <= 0.0;
+    vec2 local = mix(aBbox.xy, aBbox.zw, corner);
+    vec2 pos = aOrigin.xy + aScale * local;
+
+    if (!empty) {
+        pos += dilate(vec3(pos, aOrigin.z), 2.0 * corner - 1.0);
+        local = (pos - aOrigin.xy) / aScale;
+    }
+
+    vUv = empty ? corner : (local - aBbox.xy) / size;
+    vComponentsRange = aComponentsRange;
+    vPointsRange = aPointsRange;
+    vFillRule = aFillRule;
+    vExtent = aExtent;
+    vColor = aColor;
+    gl_Position = uTransform * vec4(pos, aOrigin.z, 1.f);
+}